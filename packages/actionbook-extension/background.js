@@ -304,7 +304,8 @@ async function refreshCloudTokenIfNeeded({ force = false } = {}) {
   }
 }
 
-// Lazily generate and persist a per-install deviceId (only used in cloud mode).
+// Lazily generate and persist a per-install deviceId, sent in every hello:
+// the local bridge keys concurrent extensions by it, the cloud registers it.
 async function ensureDeviceId() {
   const { deviceId } = await chrome.storage.local.get("deviceId");
   if (deviceId) return deviceId;
@@ -401,16 +402,15 @@ async function connect() {
   ws.onopen = async () => {
     wsOpened = true;
     // Local mode: tokenless, origin-validated. Cloud mode: token already went
-    // up via Sec-WebSocket-Protocol at upgrade; hello is used to report the
-    // per-install deviceId for device registry.
+    // up via Sec-WebSocket-Protocol at upgrade. Both report the per-install
+    // deviceId: the local bridge keys concurrent extensions (one per Chrome
+    // profile) by it, the cloud uses it for the device registry.
     const hello = {
       type: "hello",
       role: "extension",
       version: PROTOCOL_VERSION,
+      deviceId: await ensureDeviceId(),
     };
-    if (cfg.mode === "cloud") {
-      hello.deviceId = await ensureDeviceId();
-    }
     wsSend(hello);
 
     // Start handshake timeout - if no hello_ack within this window, treat as auth failure
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::browser::{cookies, interaction, navigation, observation, session, storage, tab, wait};
+use crate::browser::{
+    cookies, emulation, interaction, navigation, observation, profiles, session, storage, tab, wait,
+};
 use crate::extension;
 
 /// CLI → Daemon action protocol. Each variant wraps the command's Cmd type.
@@ -14,6 +16,10 @@ pub enum Action {
     Close(session::close::Cmd),
     Restart(session::restart::Cmd),
 
+    // ── Profiles ───────────────────────────────────────────────
+    ProfilesList(profiles::list::Cmd),
+    ProfilesDelete(profiles::delete::Cmd),
+
     // ── Tab management ─────────────────────────────────────────
     NewTab(tab::open::Cmd),
     BatchOpen(tab::batch_open::Cmd),
@@ -33,6 +39,8 @@ pub enum Action {
     Title(observation::title::Cmd),
     Url(observation::url::Cmd),
     Viewport(observation::viewport::Cmd),
+    Emulate(emulation::emulate::Cmd),
+    Throttle(emulation::throttle::Cmd),
     Html(observation::html::Cmd),
     Text(observation::text::Cmd),
     Value(observation::value::Cmd),
@@ -42,6 +50,8 @@ pub enum Action {
     Styles(observation::styles::Cmd),
     Describe(observation::describe::Cmd),
     State(observation::state::Cmd),
+    Exists(observation::exists::Cmd),
+    Visible(observation::visible::Cmd),
     Query(observation::query::Cmd),
     InspectPoint(observation::inspect_point::Cmd),
     Pdf(observation::pdf::Cmd),
@@ -58,6 +68,8 @@ pub enum Action {
     CookiesSet(cookies::set::Cmd),
     CookiesDelete(cookies::delete::Cmd),
     CookiesClear(cookies::clear::Cmd),
+    CookiesExport(cookies::export::Cmd),
+    CookiesImport(cookies::import::Cmd),
 
     // ── Storage ────────────────────────────────────────────────
     StorageList(storage::list::Cmd),
@@ -87,12 +99,21 @@ pub enum Action {
     Select(interaction::select::Cmd),
     Drag(interaction::drag::Cmd),
     Upload(interaction::upload::Cmd),
+    Dialog(interaction::dialog::Cmd),
     MouseMove(interaction::mouse_move::Cmd),
     CursorPosition(interaction::cursor_position::Cmd),
     Scroll(interaction::scroll::Cmd),
 }
 
 impl Action {
+    /// Session this action addresses, if any (taken from
+    /// [`Self::session_tab_label`]).
+    pub fn session_id(&self) -> Option<String> {
+        let label = self.session_tab_label();
+        let session = label.split('/').next().unwrap_or_default();
+        (!session.is_empty() && session != "-").then(|| session.to_string())
+    }
+
     /// Extract session/tab addressing for log lines.
     ///
     /// Returns e.g. `"s0/t1"`, `"s0"`, or `"-"` (for list-sessions).
@@ -115,6 +136,7 @@ impl Action {
 
             // Session-level (no tab)
             Action::StartSession(_) | Action::ListSessions(_) => "-".into(),
+            Action::ProfilesList(_) | Action::ProfilesDelete(_) => "-".into(),
             Action::SessionStatus(c) => s_only!(c),
             Action::Close(c) => s_only!(c),
             Action::Restart(c) => s_only!(c),
@@ -138,6 +160,8 @@ impl Action {
             Action::Title(c) => st!(c),
             Action::Url(c) => st!(c),
             Action::Viewport(c) => st!(c),
+            Action::Emulate(c) => st!(c),
+            Action::Throttle(c) => st!(c),
             Action::Html(c) => st!(c),
             Action::Text(c) => st!(c),
             Action::Value(c) => st!(c),
@@ -147,6 +171,8 @@ impl Action {
             Action::Styles(c) => st!(c),
             Action::Describe(c) => st!(c),
             Action::State(c) => st!(c),
+            Action::Exists(c) => st!(c),
+            Action::Visible(c) => st!(c),
             Action::Query(c) => format!("{}/{}", c.session(), c.tab()),
             Action::InspectPoint(c) => st!(c),
             Action::Pdf(c) => st!(c),
@@ -163,6 +189,8 @@ impl Action {
             Action::CookiesSet(c) => s_only!(c),
             Action::CookiesDelete(c) => s_only!(c),
             Action::CookiesClear(c) => s_only!(c),
+            Action::CookiesExport(c) => s_only!(c),
+            Action::CookiesImport(c) => s_only!(c),
 
             // Storage
             Action::StorageList(c) => st!(c),
@@ -189,6 +217,7 @@ impl Action {
             Action::Select(c) => st!(c),
             Action::Drag(c) => st!(c),
             Action::Upload(c) => st!(c),
+            Action::Dialog(c) => st!(c),
             Action::MouseMove(c) => st!(c),
             Action::CursorPosition(c) => st!(c),
             Action::Scroll(c) => st!(c),
@@ -204,6 +233,8 @@ impl Action {
             Action::SessionStatus(_) => session::status::COMMAND_NAME,
             Action::Close(_) => session::close::COMMAND_NAME,
             Action::Restart(_) => session::restart::COMMAND_NAME,
+            Action::ProfilesList(_) => profiles::list::COMMAND_NAME,
+            Action::ProfilesDelete(_) => profiles::delete::COMMAND_NAME,
             Action::NewTab(_) => tab::open::COMMAND_NAME,
             Action::BatchOpen(_) => tab::batch_open::COMMAND_NAME,
             Action::CloseTab(_) => tab::close::COMMAND_NAME,
@@ -218,6 +249,8 @@ impl Action {
             Action::Title(_) => observation::title::COMMAND_NAME,
             Action::Url(_) => observation::url::COMMAND_NAME,
             Action::Viewport(_) => observation::viewport::COMMAND_NAME,
+            Action::Emulate(_) => emulation::emulate::COMMAND_NAME,
+            Action::Throttle(_) => emulation::throttle::COMMAND_NAME,
             Action::Html(_) => observation::html::COMMAND_NAME,
             Action::Text(_) => observation::text::COMMAND_NAME,
             Action::Value(_) => observation::value::COMMAND_NAME,
@@ -227,6 +260,8 @@ impl Action {
             Action::Styles(_) => observation::styles::COMMAND_NAME,
             Action::Describe(_) => observation::describe::COMMAND_NAME,
             Action::State(_) => observation::state::COMMAND_NAME,
+            Action::Exists(_) => observation::exists::COMMAND_NAME,
+            Action::Visible(_) => observation::visible::COMMAND_NAME,
             Action::Query(_) => observation::query::COMMAND_NAME,
             Action::InspectPoint(_) => observation::inspect_point::COMMAND_NAME,
             Action::Pdf(_) => observation::pdf::COMMAND_NAME,
@@ -241,6 +276,8 @@ impl Action {
             Action::CookiesSet(_) => cookies::set::COMMAND_NAME,
             Action::CookiesDelete(_) => cookies::delete::COMMAND_NAME,
             Action::CookiesClear(_) => cookies::clear::COMMAND_NAME,
+            Action::CookiesExport(_) => cookies::export::COMMAND_NAME,
+            Action::CookiesImport(_) => cookies::import::COMMAND_NAME,
             Action::StorageList(cmd) => storage::list::command_name(cmd.kind),
             Action::StorageGet(cmd) => storage::get::command_name(cmd.kind),
             Action::StorageSet(cmd) => storage::set::command_name(cmd.kind),
@@ -261,6 +298,7 @@ impl Action {
             Action::Select(_) => interaction::select::COMMAND_NAME,
             Action::Drag(_) => interaction::drag::COMMAND_NAME,
             Action::Upload(_) => interaction::upload::COMMAND_NAME,
+            Action::Dialog(_) => interaction::dialog::COMMAND_NAME,
             Action::MouseMove(_) => interaction::mouse_move::COMMAND_NAME,
             Action::CursorPosition(_) => interaction::cursor_position::COMMAND_NAME,
             Action::Scroll(_) => interaction::scroll::COMMAND_NAME,
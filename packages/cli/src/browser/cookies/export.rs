@@ -0,0 +1,128 @@
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::action_result::ActionResult;
+use crate::daemon::registry::SharedRegistry;
+use crate::output::ResponseContext;
+
+use super::{map_cookie, normalize_domain};
+
+/// Export cookies to a JSON file
+#[derive(Args, Debug, Clone, Serialize, Deserialize)]
+#[command(after_help = "\
+Examples:
+  actionbook browser cookies export cookies.json --session s1
+  actionbook browser cookies export github.json --session s1 --domain github.com
+
+Writes a JSON array in the same shape as `cookies list` items, including
+domain, path, expiry and secure/http_only/same_site flags.
+Load it into another session with `cookies import`.")]
+pub struct Cmd {
+    /// Output file path
+    #[arg()]
+    pub path: String,
+    /// Session ID
+    #[arg(long)]
+    #[serde(rename = "session_id")]
+    pub session: String,
+    /// Only export cookies for this domain
+    #[arg(long)]
+    pub domain: Option<String>,
+}
+
+pub const COMMAND_NAME: &str = "browser cookies export";
+
+pub fn context(cmd: &Cmd, result: &ActionResult) -> Option<ResponseContext> {
+    if let ActionResult::Fatal { code, .. } = result
+        && code == "SESSION_NOT_FOUND"
+    {
+        return None;
+    }
+    Some(ResponseContext {
+        session_id: cmd.session.clone(),
+        tab_id: None,
+        window_id: None,
+        url: None,
+        title: None,
+    })
+}
+
+pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
+    let (cdp, target_id) = {
+        let reg = registry.lock().await;
+        let entry = match reg.get(&cmd.session) {
+            Some(e) => e,
+            None => {
+                return ActionResult::fatal_with_hint(
+                    "SESSION_NOT_FOUND",
+                    format!("session '{}' not found", cmd.session),
+                    "run `actionbook browser list-sessions` to see available sessions",
+                );
+            }
+        };
+        let cdp = match entry.cdp.clone() {
+            Some(c) => c,
+            None => {
+                return ActionResult::fatal(
+                    "INTERNAL_ERROR",
+                    format!("no CDP connection for session '{}'", cmd.session),
+                );
+            }
+        };
+        let target_id = match entry.tabs.first() {
+            Some(t) => t.native_id.clone(),
+            None => {
+                return ActionResult::fatal(
+                    "NO_TAB",
+                    format!("no active tab in session '{}'", cmd.session),
+                );
+            }
+        };
+        (cdp, target_id)
+    };
+
+    let resp = match cdp
+        .execute_on_tab(&target_id, "Network.getAllCookies", json!({}))
+        .await
+    {
+        Ok(v) => v,
+        Err(e) => return ActionResult::fatal("CDP_ERROR", e.to_string()),
+    };
+
+    let empty = vec![];
+    let items: Vec<_> = resp
+        .pointer("/result/cookies")
+        .and_then(|v| v.as_array())
+        .unwrap_or(&empty)
+        .iter()
+        .map(map_cookie)
+        .filter(|c| match cmd.domain {
+            Some(ref filter_domain) => {
+                let cookie_domain = c.get("domain").and_then(|v| v.as_str()).unwrap_or("");
+                normalize_domain(cookie_domain) == normalize_domain(filter_domain)
+            }
+            None => true,
+        })
+        .collect();
+
+    let body = match serde_json::to_string_pretty(&items) {
+        Ok(s) => s,
+        Err(e) => return ActionResult::fatal("INTERNAL_ERROR", e.to_string()),
+    };
+    let out_path = std::path::PathBuf::from(&cmd.path);
+    let out_path = std::path::absolute(&out_path).unwrap_or(out_path);
+    let path_str = out_path.to_string_lossy().to_string();
+    if let Err(e) = std::fs::write(&out_path, body) {
+        return ActionResult::fatal(
+            "ARTIFACT_WRITE_FAILED",
+            format!("failed to write cookies to {path_str}: {e}"),
+        );
+    }
+
+    ActionResult::ok(json!({
+        "action": "export",
+        "affected": items.len(),
+        "path": path_str,
+    }))
+}
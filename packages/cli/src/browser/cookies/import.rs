@@ -0,0 +1,164 @@
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::action_result::ActionResult;
+use crate::daemon::registry::SharedRegistry;
+use crate::output::ResponseContext;
+
+use super::cookie_param;
+
+/// Import cookies from a JSON file
+#[derive(Args, Debug, Clone, Serialize, Deserialize)]
+#[command(after_help = "\
+Examples:
+  actionbook browser cookies export cookies.json --session s1
+  actionbook browser cookies import cookies.json --session s2
+
+Reads a JSON array of cookies (as written by `cookies export`) and sets them all,
+keeping domain, path, expiry and secure/http_only/same_site flags. A domain
+without a leading dot is imported as a host-only cookie, as it was exported.
+The `cookies list --json` data object ({\"items\": [...]}) is also accepted.")]
+pub struct Cmd {
+    /// Input file path
+    #[arg()]
+    pub path: String,
+    /// Session ID
+    #[arg(long)]
+    #[serde(rename = "session_id")]
+    pub session: String,
+}
+
+pub const COMMAND_NAME: &str = "browser cookies import";
+
+pub fn context(cmd: &Cmd, result: &ActionResult) -> Option<ResponseContext> {
+    if let ActionResult::Fatal { code, .. } = result
+        && code == "SESSION_NOT_FOUND"
+    {
+        return None;
+    }
+    Some(ResponseContext {
+        session_id: cmd.session.clone(),
+        tab_id: None,
+        window_id: None,
+        url: None,
+        title: None,
+    })
+}
+
+/// Parse the file contents into `Network.setCookies` params.
+fn parse_cookie_file(contents: &str) -> Result<Vec<Value>, String> {
+    let doc: Value = serde_json::from_str(contents).map_err(|e| format!("invalid JSON: {e}"))?;
+    let items = match doc {
+        Value::Array(items) => items,
+        Value::Object(mut obj) => match obj.remove("items") {
+            Some(Value::Array(items)) => items,
+            _ => return Err("expected a JSON array of cookies".to_string()),
+        },
+        _ => return Err("expected a JSON array of cookies".to_string()),
+    };
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, c)| cookie_param(c).map_err(|e| format!("cookie #{}: {e}", i + 1)))
+        .collect()
+}
+
+pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
+    let contents = match std::fs::read_to_string(&cmd.path) {
+        Ok(s) => s,
+        Err(e) => {
+            return ActionResult::fatal(
+                "INVALID_ARGUMENT",
+                format!("failed to read '{}': {e}", cmd.path),
+            );
+        }
+    };
+    let cookies = match parse_cookie_file(&contents) {
+        Ok(c) => c,
+        Err(e) => {
+            return ActionResult::fatal_with_hint(
+                "INVALID_ARGUMENT",
+                format!("cannot import '{}': {e}", cmd.path),
+                "use a file written by `actionbook browser cookies export`",
+            );
+        }
+    };
+
+    let (cdp, target_id) = {
+        let reg = registry.lock().await;
+        let entry = match reg.get(&cmd.session) {
+            Some(e) => e,
+            None => {
+                return ActionResult::fatal_with_hint(
+                    "SESSION_NOT_FOUND",
+                    format!("session '{}' not found", cmd.session),
+                    "run `actionbook browser list-sessions` to see available sessions",
+                );
+            }
+        };
+        let cdp = match entry.cdp.clone() {
+            Some(c) => c,
+            None => {
+                return ActionResult::fatal(
+                    "INTERNAL_ERROR",
+                    format!("no CDP connection for session '{}'", cmd.session),
+                );
+            }
+        };
+        let target_id = match entry.tabs.first() {
+            Some(t) => t.native_id.clone(),
+            None => {
+                return ActionResult::fatal(
+                    "NO_TAB",
+                    format!("no active tab in session '{}'", cmd.session),
+                );
+            }
+        };
+        (cdp, target_id)
+    };
+
+    let count = cookies.len();
+    if count > 0
+        && let Err(e) = cdp
+            .execute_on_tab(
+                &target_id,
+                "Network.setCookies",
+                json!({ "cookies": cookies }),
+            )
+            .await
+    {
+        return ActionResult::fatal("CDP_ERROR", e.to_string());
+    }
+
+    ActionResult::ok(json!({
+        "action": "import",
+        "affected": count,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_cookie_file_accepts_array_and_list_output() {
+        let array = r#"[{"name":"a","value":"1","domain":".x.com","path":"/","secure":true,"http_only":false,"same_site":"","expires":null}]"#;
+        let params = parse_cookie_file(array).unwrap();
+        assert_eq!(params.len(), 1);
+        assert_eq!(params[0]["domain"], ".x.com");
+        assert_eq!(params[0]["secure"], true);
+
+        let list = r#"{"items":[{"name":"a","value":"1","domain":"x.com"},{"name":"b","value":"2","domain":"y.com"}]}"#;
+        assert_eq!(parse_cookie_file(list).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn parse_cookie_file_reports_bad_entries() {
+        assert!(parse_cookie_file("not json").is_err());
+        assert!(parse_cookie_file(r#"{"name":"a"}"#).is_err());
+        let err =
+            parse_cookie_file(r#"[{"name":"a","domain":"x.com"},{"value":"b"}]"#).unwrap_err();
+        assert!(err.starts_with("cookie #2:"), "got {err}");
+    }
+}
@@ -1,6 +1,8 @@
 pub mod clear;
 pub mod delete;
+pub mod export;
 pub mod get;
+pub mod import;
 pub mod list;
 pub mod set;
 
@@ -25,7 +27,154 @@ pub fn map_cookie(c: &Value) -> Value {
     })
 }
 
+/// Map a cookie in our canonical shape back to a CDP `CookieParam`.
+///
+/// Also accepts CDP's own camelCase keys (`httpOnly`, `sameSite`) so exports
+/// from DevTools-based tools import unchanged. A null/missing/negative
+/// `expires` yields a session cookie.
+///
+/// CDP reports host-only cookies with a bare `domain` (no leading dot), but
+/// `Network.setCookie` with a `domain` always creates a domain cookie. Those
+/// are set by `url` instead so they stay host-only.
+pub fn cookie_param(c: &Value) -> Result<Value, String> {
+    let name = c
+        .get("name")
+        .and_then(|v| v.as_str())
+        .ok_or("cookie is missing a string 'name'")?;
+    let value = c.get("value").and_then(|v| v.as_str()).unwrap_or("");
+    let domain = c
+        .get("domain")
+        .and_then(|v| v.as_str())
+        .filter(|d| !d.is_empty())
+        .ok_or_else(|| format!("cookie '{name}' is missing 'domain'"))?;
+    let flag = |snake: &str, camel: &str| {
+        c.get(snake)
+            .or_else(|| c.get(camel))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    };
+
+    let path = c.get("path").and_then(|v| v.as_str()).unwrap_or("/");
+    let secure = flag("secure", "secure");
+
+    let mut param = serde_json::json!({
+        "name": name,
+        "value": value,
+        "path": path,
+        "secure": secure,
+        "httpOnly": flag("http_only", "httpOnly"),
+    });
+    if domain.starts_with('.') {
+        param["domain"] = Value::from(domain);
+    } else {
+        let scheme = if secure { "https" } else { "http" };
+        param["url"] = Value::from(format!("{scheme}://{domain}{path}"));
+    }
+    if let Some(ss) = c
+        .get("same_site")
+        .or_else(|| c.get("sameSite"))
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+    {
+        param["sameSite"] = Value::from(ss);
+    }
+    if let Some(exp) = c
+        .get("expires")
+        .and_then(|v| v.as_f64())
+        .filter(|e| *e >= 0.0)
+    {
+        param["expires"] = Value::from(exp);
+    }
+    Ok(param)
+}
+
 /// Normalize a cookie domain for comparison by stripping a leading dot and lowercasing.
 pub fn normalize_domain(d: &str) -> String {
     d.trim_start_matches('.').to_lowercase()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn cookie_param_round_trips_map_cookie() {
+        let cdp = json!({
+            "name": "sid",
+            "value": "abc",
+            "domain": ".example.com",
+            "path": "/app",
+            "expires": 1_900_000_000.0,
+            "httpOnly": true,
+            "secure": true,
+            "sameSite": "Lax",
+            "size": 6,
+            "session": false
+        });
+        assert_eq!(
+            cookie_param(&map_cookie(&cdp)).unwrap(),
+            json!({
+                "name": "sid",
+                "value": "abc",
+                "domain": ".example.com",
+                "path": "/app",
+                "secure": true,
+                "httpOnly": true,
+                "sameSite": "Lax",
+                "expires": 1_900_000_000.0,
+            })
+        );
+    }
+
+    #[test]
+    fn cookie_param_keeps_session_cookies_and_accepts_camel_case() {
+        let param = cookie_param(&json!({
+            "name": "tmp",
+            "value": "1",
+            "domain": "example.com",
+            "httpOnly": true,
+            "expires": -1
+        }))
+        .unwrap();
+        assert_eq!(param["httpOnly"], true);
+        assert_eq!(param["path"], "/");
+        assert_eq!(param["url"], "http://example.com/");
+        assert!(param.get("domain").is_none());
+        assert!(param.get("expires").is_none());
+        assert!(param.get("sameSite").is_none());
+    }
+
+    #[test]
+    fn cookie_param_round_trips_host_only_cookie() {
+        let cdp = json!({
+            "name": "sid",
+            "value": "abc",
+            "domain": "app.example.com",
+            "path": "/app",
+            "expires": -1,
+            "httpOnly": false,
+            "secure": true,
+            "sameSite": "Strict",
+            "session": true
+        });
+        assert_eq!(
+            cookie_param(&map_cookie(&cdp)).unwrap(),
+            json!({
+                "name": "sid",
+                "value": "abc",
+                "url": "https://app.example.com/app",
+                "path": "/app",
+                "secure": true,
+                "httpOnly": false,
+                "sameSite": "Strict",
+            })
+        );
+    }
+
+    #[test]
+    fn cookie_param_requires_name_and_domain() {
+        assert!(cookie_param(&json!({ "value": "x", "domain": "a.com" })).is_err());
+        assert!(cookie_param(&json!({ "name": "x", "value": "x" })).is_err());
+    }
+}
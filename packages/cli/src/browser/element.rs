@@ -5,8 +5,8 @@
 //!
 //! Selector dispatch:
 //! 1. **CSS selector** — default path, `DOM.querySelector`.
-//! 2. **XPath** — prefix `//` or `/`, `Runtime.evaluate`.
-//! 3. **Snapshot ref** — prefix `@e`, e.g. `@e5`, via RefCache + CDP.
+//! 2. **XPath** — prefix `/` (incl. `//`) or `(/`, `Runtime.evaluate`.
+//! 3. **Snapshot ref** — prefix `@e` or `ref=e`, e.g. `@e5`, via RefCache + CDP.
 //!
 //! iframe support: after resolving an `@eN` ref, `resolved_frame_id` is set
 //! so that subsequent `execute_on_element()` calls route to the correct CDP
@@ -86,7 +86,42 @@ impl TabContext {
 
     /// Selector → CDP `nodeId`. Sets `resolved_frame_id` for @eN refs.
     pub async fn resolve_node(&mut self, selector: &str) -> Result<i64, ActionResult> {
-        if selector.starts_with("@e") {
+        self.resolve_node_with_root(selector, None).await
+    }
+
+    /// Main-frame `DOM.getDocument` → root `nodeId`.
+    ///
+    /// Pollers fetch this once and pass it to [`Self::resolve_node_in`]
+    /// until the document is replaced (`DOM.documentUpdated`).
+    pub async fn document_root(&self) -> Result<i64, ActionResult> {
+        let doc = self
+            .cdp
+            .execute_on_tab(&self.target_id, "DOM.getDocument", json!({}))
+            .await
+            .map_err(|e| cdp_error_to_result(e, "CDP_ERROR"))?;
+        Ok(doc
+            .pointer("/result/root/nodeId")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(0))
+    }
+
+    /// Like [`Self::resolve_node`], but against an already-fetched main-frame
+    /// document root, so no `DOM.getDocument` round-trip is made. @refs in
+    /// iframes still fetch their own frame's document.
+    pub async fn resolve_node_in(
+        &mut self,
+        selector: &str,
+        root_id: i64,
+    ) -> Result<i64, ActionResult> {
+        self.resolve_node_with_root(selector, Some(root_id)).await
+    }
+
+    async fn resolve_node_with_root(
+        &mut self,
+        selector: &str,
+        root_id: Option<i64>,
+    ) -> Result<i64, ActionResult> {
+        if is_snapshot_ref(selector) {
             let (node_id, frame_id) = resolve_ref(
                 &self.cdp,
                 &self.target_id,
@@ -94,16 +129,17 @@ impl TabContext {
                 &self.registry,
                 &self.session_id,
                 &self.tab_id,
+                root_id.is_some(),
             )
             .await?;
             self.resolved_frame_id = frame_id;
             Ok(node_id)
-        } else if selector.starts_with("//") || selector.starts_with('/') {
+        } else if selector.starts_with('/') || selector.starts_with("(/") {
             self.resolved_frame_id = None;
-            resolve_xpath(&self.cdp, &self.target_id, selector).await
+            resolve_xpath(&self.cdp, &self.target_id, selector, root_id.is_some()).await
         } else {
             self.resolved_frame_id = None;
-            resolve_css(&self.cdp, &self.target_id, selector).await
+            resolve_css(&self.cdp, &self.target_id, selector, root_id).await
         }
     }
 
@@ -458,21 +494,26 @@ pub fn element_not_found(selector: &str) -> ActionResult {
 
 // ── Private resolvers ──────────────────────────────────────────────
 
-/// CSS selector → nodeId via `DOM.querySelector`.
+/// CSS selector → nodeId via `DOM.querySelector`. Fetches the document
+/// unless the caller already holds its root `nodeId`.
 async fn resolve_css(
     cdp: &CdpSession,
     target_id: &str,
     selector: &str,
+    root_id: Option<i64>,
 ) -> Result<i64, ActionResult> {
-    let doc = cdp
-        .execute_on_tab(target_id, "DOM.getDocument", json!({}))
-        .await
-        .map_err(|e| cdp_error_to_result(e, "CDP_ERROR"))?;
-
-    let root_id = doc
-        .pointer("/result/root/nodeId")
-        .and_then(|v| v.as_i64())
-        .unwrap_or(0);
+    let root_id = match root_id {
+        Some(id) => id,
+        None => {
+            let doc = cdp
+                .execute_on_tab(target_id, "DOM.getDocument", json!({}))
+                .await
+                .map_err(|e| cdp_error_to_result(e, "CDP_ERROR"))?;
+            doc.pointer("/result/root/nodeId")
+                .and_then(|v| v.as_i64())
+                .unwrap_or(0)
+        }
+    };
 
     let query = match cdp
         .execute_on_tab(
@@ -513,15 +554,27 @@ async fn resolve_css(
     Ok(node_id)
 }
 
+/// Whether a selector names a snapshot ref: `@eN`, or `ref=eN` as printed
+/// in snapshot output.
+fn is_snapshot_ref(selector: &str) -> bool {
+    selector.starts_with("@e") || selector.starts_with("ref=e")
+}
+
 /// XPath expression → nodeId via `Runtime.evaluate` + `DOM.requestNode`.
+///
+/// `DOM.requestNode` needs the document loaded in the DOM agent; skip the
+/// fetch when the caller already did it.
 async fn resolve_xpath(
     cdp: &CdpSession,
     target_id: &str,
     selector: &str,
+    document_loaded: bool,
 ) -> Result<i64, ActionResult> {
-    cdp.execute_on_tab(target_id, "DOM.getDocument", json!({}))
-        .await
-        .map_err(|e| cdp_error_to_result(e, "CDP_ERROR"))?;
+    if !document_loaded {
+        cdp.execute_on_tab(target_id, "DOM.getDocument", json!({}))
+            .await
+            .map_err(|e| cdp_error_to_result(e, "CDP_ERROR"))?;
+    }
 
     let xpath_json = serde_json::to_string(selector).unwrap_or_default();
     let js = format!(
@@ -579,8 +632,12 @@ async fn resolve_ref(
     registry: &SharedRegistry,
     session_id: &str,
     tab_id: &str,
+    document_loaded: bool,
 ) -> Result<(i64, Option<String>), ActionResult> {
-    let ref_id = selector.strip_prefix('@').unwrap_or(selector);
+    let ref_id = selector
+        .strip_prefix('@')
+        .or_else(|| selector.strip_prefix("ref="))
+        .unwrap_or(selector);
 
     if !ref_id.starts_with('e') || ref_id.len() < 2 || ref_id[1..].parse::<u64>().is_err() {
         return Err(ActionResult::fatal(
@@ -613,16 +670,19 @@ async fn resolve_ref(
         )
     })?;
 
-    // Get document on the correct frame session
-    execute_for_frame(
-        cdp,
-        target_id,
-        frame_id.as_deref(),
-        "DOM.getDocument",
-        json!({}),
-    )
-    .await
-    .map_err(|e| cdp_error_to_result(e, "CDP_ERROR"))?;
+    // Get document on the correct frame session; the caller's root only
+    // covers the main frame.
+    if !document_loaded || frame_id.is_some() {
+        execute_for_frame(
+            cdp,
+            target_id,
+            frame_id.as_deref(),
+            "DOM.getDocument",
+            json!({}),
+        )
+        .await
+        .map_err(|e| cdp_error_to_result(e, "CDP_ERROR"))?;
+    }
 
     // Try direct resolution for real backendNodeIds (> 0)
     if backend_node_id > 0
@@ -792,3 +852,17 @@ pub fn parse_target(input: &str) -> Result<ClickTarget, ActionResult> {
 
     Ok(ClickTarget::Coordinates(x, y))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::is_snapshot_ref;
+
+    #[test]
+    fn snapshot_ref_accepts_at_and_ref_equals_forms() {
+        assert!(is_snapshot_ref("@e3"));
+        assert!(is_snapshot_ref("ref=e3"));
+        assert!(!is_snapshot_ref("e3"));
+        assert!(!is_snapshot_ref("#ref"));
+        assert!(!is_snapshot_ref("[ref=e3]"));
+    }
+}
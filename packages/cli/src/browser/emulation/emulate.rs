@@ -0,0 +1,497 @@
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::action_result::ActionResult;
+use crate::daemon::cdp_session::{CdpSession, cdp_error_to_result, get_cdp_and_target};
+use crate::daemon::registry::SharedRegistry;
+use crate::output::ResponseContext;
+
+const IOS_UA: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 16_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.0 Mobile/15E148 Safari/604.1";
+const IPAD_UA: &str = "Mozilla/5.0 (iPad; CPU OS 16_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.0 Mobile/15E148 Safari/604.1";
+const PIXEL_7_UA: &str = "Mozilla/5.0 (Linux; Android 13; Pixel 7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/116.0.0.0 Mobile Safari/537.36";
+const GALAXY_S23_UA: &str = "Mozilla/5.0 (Linux; Android 13; SM-S911B) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/116.0.0.0 Mobile Safari/537.36";
+
+/// A named device: CSS-pixel viewport, pixel ratio, mobile flag and UA.
+/// `user_agent: None` restores the session's own User-Agent, undoing any
+/// earlier mobile emulation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Device {
+    pub name: &'static str,
+    pub width: u32,
+    pub height: u32,
+    pub scale: f64,
+    pub mobile: bool,
+    pub user_agent: Option<&'static str>,
+}
+
+pub const DEVICES: &[Device] = &[
+    Device {
+        name: "iPhone SE",
+        width: 375,
+        height: 667,
+        scale: 2.0,
+        mobile: true,
+        user_agent: Some(IOS_UA),
+    },
+    Device {
+        name: "iPhone 14",
+        width: 390,
+        height: 844,
+        scale: 3.0,
+        mobile: true,
+        user_agent: Some(IOS_UA),
+    },
+    Device {
+        name: "iPhone 14 Pro Max",
+        width: 430,
+        height: 932,
+        scale: 3.0,
+        mobile: true,
+        user_agent: Some(IOS_UA),
+    },
+    Device {
+        name: "Pixel 7",
+        width: 412,
+        height: 915,
+        scale: 2.625,
+        mobile: true,
+        user_agent: Some(PIXEL_7_UA),
+    },
+    Device {
+        name: "Galaxy S23",
+        width: 360,
+        height: 780,
+        scale: 3.0,
+        mobile: true,
+        user_agent: Some(GALAXY_S23_UA),
+    },
+    Device {
+        name: "iPad Air",
+        width: 820,
+        height: 1180,
+        scale: 2.0,
+        mobile: true,
+        user_agent: Some(IPAD_UA),
+    },
+    Device {
+        name: "Laptop",
+        width: 1366,
+        height: 768,
+        scale: 1.0,
+        mobile: false,
+        user_agent: None,
+    },
+    Device {
+        name: "Desktop",
+        width: 1920,
+        height: 1080,
+        scale: 1.0,
+        mobile: false,
+        user_agent: None,
+    },
+];
+
+/// Emulate a device or set the viewport size
+#[derive(Args, Debug, Clone, Serialize, Deserialize)]
+#[command(after_help = "\
+Examples:
+  actionbook browser emulate \"iPhone 14\" --session s1 --tab t1
+  actionbook browser emulate pixel-7 --session s1 --tab t1
+  actionbook browser emulate --width 1024 --height 768 --session s1 --tab t1
+  actionbook browser emulate --reset --session s1 --tab t1
+
+Devices: iPhone SE, iPhone 14, iPhone 14 Pro Max, Pixel 7, Galaxy S23,
+iPad Air, Laptop, Desktop (case-insensitive; '-' may replace spaces).
+--width/--height/--scale/--mobile override the device values.
+Mobile devices also enable touch events and switch the User-Agent; desktop
+devices and custom sizes put the session's own User-Agent back.
+--reset restores the window's own size and the session's User-Agent.")]
+pub struct Cmd {
+    /// Device preset name
+    pub device: Option<String>,
+    /// Session ID
+    #[arg(long)]
+    #[serde(rename = "session_id")]
+    pub session: String,
+    /// Tab ID
+    #[arg(long)]
+    #[serde(rename = "tab_id")]
+    pub tab: String,
+    /// Viewport width in CSS pixels
+    #[arg(long)]
+    pub width: Option<u32>,
+    /// Viewport height in CSS pixels
+    #[arg(long)]
+    pub height: Option<u32>,
+    /// Device pixel ratio (e.g. 2 for retina)
+    #[arg(long)]
+    pub scale: Option<f64>,
+    /// Emulate a mobile device (meta viewport, touch events)
+    #[arg(long)]
+    #[serde(default)]
+    pub mobile: bool,
+    /// Clear any emulation and return to the real window
+    #[arg(long, conflicts_with_all = ["device", "width", "height", "scale", "mobile"])]
+    #[serde(default)]
+    pub reset: bool,
+}
+
+pub const COMMAND_NAME: &str = "browser emulate";
+
+pub fn context(cmd: &Cmd, result: &ActionResult) -> Option<ResponseContext> {
+    if let ActionResult::Fatal { code, .. } = result
+        && code == "SESSION_NOT_FOUND"
+    {
+        return None;
+    }
+    Some(ResponseContext {
+        session_id: cmd.session.clone(),
+        tab_id: Some(cmd.tab.clone()),
+        window_id: None,
+        url: None,
+        title: None,
+    })
+}
+
+/// Look up a device preset. Case-insensitive; `-` and `_` match spaces.
+pub fn find_device(name: &str) -> Option<&'static Device> {
+    let normalize = |s: &str| {
+        s.to_ascii_lowercase()
+            .replace(['-', '_'], " ")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let wanted = normalize(name);
+    DEVICES.iter().find(|d| normalize(d.name) == wanted)
+}
+
+/// Merge the device preset (if any) with explicit flags into final metrics.
+fn resolve_metrics(cmd: &Cmd) -> Result<Device, ActionResult> {
+    let base = match cmd.device.as_deref() {
+        Some(name) => *find_device(name).ok_or_else(|| {
+            let names: Vec<&str> = DEVICES.iter().map(|d| d.name).collect();
+            ActionResult::fatal_with_hint(
+                "INVALID_ARGUMENT",
+                format!("unknown device '{name}'"),
+                format!("available devices: {}", names.join(", ")),
+            )
+        })?,
+        None => {
+            let (Some(width), Some(height)) = (cmd.width, cmd.height) else {
+                return Err(ActionResult::fatal_with_hint(
+                    "INVALID_ARGUMENT",
+                    "emulate needs a device name or both --width and --height",
+                    "e.g. browser emulate \"iPhone 14\" or --width 1024 --height 768",
+                ));
+            };
+            Device {
+                name: "custom",
+                width,
+                height,
+                scale: 1.0,
+                mobile: false,
+                user_agent: None,
+            }
+        }
+    };
+
+    let metrics = Device {
+        width: cmd.width.unwrap_or(base.width),
+        height: cmd.height.unwrap_or(base.height),
+        scale: cmd.scale.unwrap_or(base.scale),
+        mobile: cmd.mobile || base.mobile,
+        ..base
+    };
+    if metrics.width == 0 || metrics.height == 0 {
+        return Err(ActionResult::fatal(
+            "INVALID_ARGUMENT",
+            "--width and --height must be greater than 0",
+        ));
+    }
+    if !(metrics.scale > 0.0 && metrics.scale.is_finite()) {
+        return Err(ActionResult::fatal(
+            "INVALID_ARGUMENT",
+            "--scale must be a positive number",
+        ));
+    }
+    Ok(metrics)
+}
+
+/// The stealth UA if the session has one, otherwise the browser's own UA.
+/// Applied whenever no device User-Agent is wanted, so a previous mobile
+/// emulation doesn't leave its UA behind.
+async fn session_user_agent(
+    cdp: &CdpSession,
+    registry: &SharedRegistry,
+    session: &str,
+) -> Option<String> {
+    let stealth_ua = registry
+        .lock()
+        .await
+        .get(session)
+        .and_then(|e| e.stealth_ua.clone());
+    match stealth_ua {
+        Some(ua) => Some(ua),
+        None => cdp
+            .execute_browser("Browser.getVersion", json!({}))
+            .await
+            .ok()
+            .and_then(|v| v["result"]["userAgent"].as_str().map(String::from)),
+    }
+}
+
+pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
+    let metrics = if cmd.reset {
+        None
+    } else {
+        match resolve_metrics(cmd) {
+            Ok(m) => Some(m),
+            Err(e) => return e,
+        }
+    };
+
+    let (cdp, target_id) = match get_cdp_and_target(registry, &cmd.session, &cmd.tab).await {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    let Some(m) = metrics else {
+        if let Err(e) = cdp
+            .execute_on_tab(
+                &target_id,
+                "Emulation.clearDeviceMetricsOverride",
+                json!({}),
+            )
+            .await
+        {
+            return cdp_error_to_result(e, "CDP_ERROR");
+        }
+        let _ = cdp
+            .execute_on_tab(
+                &target_id,
+                "Emulation.setTouchEmulationEnabled",
+                json!({ "enabled": false }),
+            )
+            .await;
+        if let Some(ua) = session_user_agent(&cdp, registry, &cmd.session).await {
+            let _ = cdp
+                .execute_on_tab(
+                    &target_id,
+                    "Emulation.setUserAgentOverride",
+                    json!({ "userAgent": ua }),
+                )
+                .await;
+        }
+        return ActionResult::ok(json!({
+            "action": "emulate",
+            "reset": true,
+        }));
+    };
+
+    if let Err(e) = cdp
+        .execute_on_tab(
+            &target_id,
+            "Emulation.setDeviceMetricsOverride",
+            json!({
+                "width": m.width,
+                "height": m.height,
+                "deviceScaleFactor": m.scale,
+                "mobile": m.mobile,
+            }),
+        )
+        .await
+    {
+        return cdp_error_to_result(e, "CDP_ERROR");
+    }
+    if let Err(e) = cdp
+        .execute_on_tab(
+            &target_id,
+            "Emulation.setTouchEmulationEnabled",
+            json!({ "enabled": m.mobile }),
+        )
+        .await
+    {
+        return cdp_error_to_result(e, "CDP_ERROR");
+    }
+    let user_agent = match m.user_agent {
+        Some(ua) => Some(ua.to_string()),
+        None => session_user_agent(&cdp, registry, &cmd.session).await,
+    };
+    if let Some(ua) = user_agent
+        && let Err(e) = cdp
+            .execute_on_tab(
+                &target_id,
+                "Emulation.setUserAgentOverride",
+                json!({ "userAgent": ua }),
+            )
+            .await
+    {
+        return cdp_error_to_result(e, "CDP_ERROR");
+    }
+
+    ActionResult::ok(json!({
+        "action": "emulate",
+        "device": m.name,
+        "width": m.width,
+        "height": m.height,
+        "device_scale_factor": m.scale,
+        "mobile": m.mobile,
+        "user_agent": m.user_agent,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::registry::{self, SessionEntry, SessionState};
+    use crate::types::{Mode, SessionId};
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::Message;
+
+    fn cmd(device: Option<&str>) -> Cmd {
+        Cmd {
+            device: device.map(String::from),
+            session: "s1".to_string(),
+            tab: "t1".to_string(),
+            width: None,
+            height: None,
+            scale: None,
+            mobile: false,
+            reset: false,
+        }
+    }
+
+    #[test]
+    fn find_device_ignores_case_and_separators() {
+        assert_eq!(find_device("iPhone 14").unwrap().name, "iPhone 14");
+        assert_eq!(find_device("iphone-14").unwrap().name, "iPhone 14");
+        assert_eq!(find_device("PIXEL_7").unwrap().name, "Pixel 7");
+        assert_eq!(
+            find_device("iphone 14 pro max").unwrap().name,
+            "iPhone 14 Pro Max"
+        );
+        assert!(find_device("Nokia 3310").is_none());
+    }
+
+    #[test]
+    fn resolve_metrics_uses_device_preset() {
+        let m = resolve_metrics(&cmd(Some("Pixel 7"))).unwrap();
+        assert_eq!((m.width, m.height), (412, 915));
+        assert_eq!(m.scale, 2.625);
+        assert!(m.mobile);
+        assert_eq!(m.user_agent, Some(PIXEL_7_UA));
+    }
+
+    #[test]
+    fn resolve_metrics_flags_override_device() {
+        let mut c = cmd(Some("iPhone 14"));
+        c.height = Some(600);
+        c.scale = Some(1.0);
+        let m = resolve_metrics(&c).unwrap();
+        assert_eq!((m.width, m.height), (390, 600));
+        assert_eq!(m.scale, 1.0);
+    }
+
+    #[test]
+    fn resolve_metrics_custom_size_requires_width_and_height() {
+        let mut c = cmd(None);
+        c.width = Some(1024);
+        assert!(resolve_metrics(&c).is_err());
+
+        c.height = Some(768);
+        let m = resolve_metrics(&c).unwrap();
+        assert_eq!((m.width, m.height, m.scale), (1024, 768, 1.0));
+        assert!(!m.mobile);
+        assert_eq!(m.user_agent, None);
+    }
+
+    #[test]
+    fn resolve_metrics_rejects_unknown_device_and_bad_values() {
+        assert!(resolve_metrics(&cmd(Some("Nokia 3310"))).is_err());
+
+        let mut c = cmd(Some("Desktop"));
+        c.scale = Some(0.0);
+        assert!(resolve_metrics(&c).is_err());
+
+        let mut c = cmd(None);
+        c.width = Some(0);
+        c.height = Some(600);
+        assert!(resolve_metrics(&c).is_err());
+    }
+
+    /// Run `emulate` against a mock extension-bridge session whose stealth UA
+    /// is `SESSION_UA`, answering every CDP call with `{}`. Returns the CDP
+    /// methods and params sent, in order.
+    async fn run_emulate(cmds: Vec<Cmd>) -> Vec<(String, serde_json::Value)> {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let (mut writer, mut reader) = ws.split();
+            let mut seen = Vec::new();
+            while let Some(Ok(raw)) = reader.next().await {
+                let Message::Text(t) = raw else { continue };
+                let msg: serde_json::Value = serde_json::from_str(t.as_ref()).unwrap();
+                seen.push((
+                    msg["method"].as_str().unwrap().to_string(),
+                    msg["params"].clone(),
+                ));
+                let reply = json!({ "id": msg["id"], "result": {} }).to_string();
+                if writer.send(Message::Text(reply.into())).await.is_err() {
+                    break;
+                }
+            }
+            seen
+        });
+
+        let cdp = CdpSession::connect(&url).await.unwrap();
+        cdp.register_extension_tab("100").await;
+        let registry = registry::new_shared_registry();
+        {
+            let mut entry = SessionEntry::starting(
+                SessionId::new_unchecked("s1"),
+                Mode::Extension,
+                false,
+                true,
+                "default".to_string(),
+            );
+            entry.status = SessionState::Running;
+            entry.stealth_ua = Some("SESSION_UA".to_string());
+            entry.cdp = Some(cdp.clone());
+            entry.push_tab("100".to_string(), String::new(), String::new());
+            registry.lock().await.insert(entry);
+        }
+        for c in cmds {
+            let result = execute(&c, &registry).await;
+            assert!(result.is_ok(), "emulate failed: {result:?}");
+        }
+        cdp.close().await;
+        server.await.unwrap()
+    }
+
+    fn user_agents(seen: &[(String, serde_json::Value)]) -> Vec<&str> {
+        seen.iter()
+            .filter(|(m, _)| m == "Emulation.setUserAgentOverride")
+            .map(|(_, p)| p["userAgent"].as_str().unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn desktop_after_mobile_restores_session_user_agent() {
+        let seen = run_emulate(vec![cmd(Some("iPhone 14")), cmd(Some("Desktop"))]).await;
+        assert_eq!(user_agents(&seen), vec![IOS_UA, "SESSION_UA"]);
+    }
+
+    #[tokio::test]
+    async fn custom_size_after_mobile_restores_session_user_agent() {
+        let mut custom = cmd(None);
+        custom.width = Some(1024);
+        custom.height = Some(768);
+        let seen = run_emulate(vec![cmd(Some("Pixel 7")), custom]).await;
+        assert_eq!(user_agents(&seen), vec![PIXEL_7_UA, "SESSION_UA"]);
+    }
+}
@@ -0,0 +1,228 @@
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::action_result::ActionResult;
+use crate::daemon::cdp_session::{cdp_error_to_result, get_cdp_and_target};
+use crate::daemon::registry::SharedRegistry;
+use crate::output::ResponseContext;
+use crate::types::Mode;
+
+/// Network condition presets (DevTools values; throughput in bytes/s).
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, clap::ValueEnum, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Preset {
+    /// 2000ms latency, ~400 kbit/s down and up
+    #[value(name = "slow-3g")]
+    #[serde(rename = "slow-3g")]
+    Slow3g,
+    /// 562.5ms latency, ~1.44 Mbit/s down, ~675 kbit/s up
+    #[value(name = "fast-3g")]
+    #[serde(rename = "fast-3g")]
+    Fast3g,
+    /// No network at all
+    Offline,
+    /// Remove throttling
+    None,
+}
+
+/// Parameters for `Network.emulateNetworkConditions`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NetworkConditions {
+    pub offline: bool,
+    pub latency_ms: f64,
+    /// Bytes per second; -1 disables throttling.
+    pub download_bps: f64,
+    /// Bytes per second; -1 disables throttling.
+    pub upload_bps: f64,
+}
+
+impl Preset {
+    pub fn conditions(self) -> NetworkConditions {
+        match self {
+            Preset::Slow3g => NetworkConditions {
+                offline: false,
+                latency_ms: 2000.0,
+                download_bps: 50_000.0,
+                upload_bps: 50_000.0,
+            },
+            Preset::Fast3g => NetworkConditions {
+                offline: false,
+                latency_ms: 562.5,
+                download_bps: 180_000.0,
+                upload_bps: 84_375.0,
+            },
+            Preset::Offline => NetworkConditions {
+                offline: true,
+                latency_ms: 0.0,
+                download_bps: 0.0,
+                upload_bps: 0.0,
+            },
+            Preset::None => NetworkConditions {
+                offline: false,
+                latency_ms: 0.0,
+                download_bps: -1.0,
+                upload_bps: -1.0,
+            },
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Preset::Slow3g => "slow-3g",
+            Preset::Fast3g => "fast-3g",
+            Preset::Offline => "offline",
+            Preset::None => "none",
+        }
+    }
+}
+
+impl NetworkConditions {
+    fn to_params(self) -> Value {
+        json!({
+            "offline": self.offline,
+            "latency": self.latency_ms,
+            "downloadThroughput": self.download_bps,
+            "uploadThroughput": self.upload_bps,
+        })
+    }
+}
+
+/// Throttle the tab's network
+#[derive(Args, Debug, Clone, Serialize, Deserialize)]
+#[command(after_help = "\
+Examples:
+  actionbook browser throttle slow-3g --session s1 --tab t1
+  actionbook browser throttle offline --session s1 --tab t1
+  actionbook browser throttle none --session s1 --tab t1
+
+Presets:
+  slow-3g   2000ms latency, 400 kbit/s down/up
+  fast-3g   562.5ms latency, 1.44 Mbit/s down, 675 kbit/s up
+  offline   all requests fail
+  none      remove throttling
+
+Not available in extension mode.")]
+pub struct Cmd {
+    /// Network preset
+    #[arg(value_enum)]
+    pub preset: Preset,
+    /// Session ID
+    #[arg(long)]
+    #[serde(rename = "session_id")]
+    pub session: String,
+    /// Tab ID
+    #[arg(long)]
+    #[serde(rename = "tab_id")]
+    pub tab: String,
+}
+
+pub const COMMAND_NAME: &str = "browser throttle";
+
+pub fn context(cmd: &Cmd, result: &ActionResult) -> Option<ResponseContext> {
+    if let ActionResult::Fatal { code, .. } = result
+        && code == "SESSION_NOT_FOUND"
+    {
+        return None;
+    }
+    Some(ResponseContext {
+        session_id: cmd.session.clone(),
+        tab_id: Some(cmd.tab.clone()),
+        window_id: None,
+        url: None,
+        title: None,
+    })
+}
+
+pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
+    let (cdp, target_id) = match get_cdp_and_target(registry, &cmd.session, &cmd.tab).await {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    let mode = registry.lock().await.get(&cmd.session).map(|e| e.mode);
+    if mode == Some(Mode::Extension) {
+        return ActionResult::fatal_with_hint(
+            "UNSUPPORTED_OPERATION",
+            "browser throttle is not supported in extension mode",
+            "use a local or cloud session, or the DevTools network panel",
+        );
+    }
+
+    let conditions = cmd.preset.conditions();
+    if let Err(e) = cdp
+        .execute_on_tab(
+            &target_id,
+            "Network.emulateNetworkConditions",
+            conditions.to_params(),
+        )
+        .await
+    {
+        return cdp_error_to_result(e, "CDP_ERROR");
+    }
+
+    ActionResult::ok(json!({
+        "action": "throttle",
+        "preset": cmd.preset.name(),
+        "conditions": {
+            "offline": conditions.offline,
+            "latency_ms": conditions.latency_ms,
+            "download_bps": conditions.download_bps,
+            "upload_bps": conditions.upload_bps,
+        },
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preset_maps_to_network_conditions_params() {
+        assert_eq!(
+            Preset::Slow3g.conditions().to_params(),
+            json!({
+                "offline": false,
+                "latency": 2000.0,
+                "downloadThroughput": 50_000.0,
+                "uploadThroughput": 50_000.0,
+            })
+        );
+        assert_eq!(
+            Preset::Fast3g.conditions().to_params(),
+            json!({
+                "offline": false,
+                "latency": 562.5,
+                "downloadThroughput": 180_000.0,
+                "uploadThroughput": 84_375.0,
+            })
+        );
+        assert_eq!(
+            Preset::Offline.conditions().to_params(),
+            json!({
+                "offline": true,
+                "latency": 0.0,
+                "downloadThroughput": 0.0,
+                "uploadThroughput": 0.0,
+            })
+        );
+        assert_eq!(
+            Preset::None.conditions().to_params(),
+            json!({
+                "offline": false,
+                "latency": 0.0,
+                "downloadThroughput": -1.0,
+                "uploadThroughput": -1.0,
+            })
+        );
+    }
+
+    #[test]
+    fn preset_names_match_cli_values() {
+        use clap::ValueEnum;
+        for preset in Preset::value_variants() {
+            let value = preset.to_possible_value().unwrap();
+            assert_eq!(value.get_name(), preset.name());
+        }
+    }
+}
@@ -28,15 +28,19 @@ Examples:
   actionbook browser click 420,310 --session s1 --tab t1
   actionbook browser click \"a.link\" --new-tab --session s1 --tab t1
   actionbook browser click \"#item\" --count 2 --session s1 --tab t1
+  actionbook browser click \"#row-3\" --button right --session s1 --tab t1
   actionbook browser click \"#close-banner\" \"#main-btn\" \"#confirm\" --session s1 --tab t1
+  actionbook browser click --text \"Sign in\" --session s1 --tab t1
 
 Accepts a CSS selector, XPath, snapshot ref (@eN), or x,y coordinates.
 When multiple selectors are provided, they are clicked sequentially in order.
 Refs come from snapshot output (e.g. [ref=e5]).
-Use --count 2 for double-click. Use --new-tab to open links in a new tab.")]
+Use --count 2 for double-click, --button right for a context menu. Use --new-tab to open links in a new tab.
+--text clicks the visible element whose label matches (case-insensitive, substring unless --exact),
+preferring buttons and links; if several match equally, the candidates are returned instead.")]
 pub struct Cmd {
     /// CSS selector, XPath, @ref, or x,y coordinates (one or more)
-    #[arg(num_args(1..))]
+    #[arg(num_args(1..), required_unless_present = "text")]
     pub selectors: Vec<String>,
     /// Session ID
     #[arg(long)]
@@ -58,6 +62,14 @@ pub struct Cmd {
     #[arg(long, default_value_t = 1)]
     #[serde(default = "default_count")]
     pub count: u32,
+    /// Click the element whose visible text matches, instead of a selector
+    #[arg(long, conflicts_with = "selectors")]
+    #[serde(default)]
+    pub text: Option<String>,
+    /// With --text, require the whole label to match
+    #[arg(long)]
+    #[serde(default)]
+    pub exact: bool,
 }
 
 pub const COMMAND_NAME: &str = "browser click";
@@ -190,8 +202,17 @@ pub(crate) async fn execute_fast_click(
 // ── Execute ────────────────────────────────────────────────────────
 
 pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
+    if let Some(ref text) = cmd.text
+        && text.trim().is_empty()
+    {
+        return ActionResult::fatal("INVALID_ARGUMENT", "--text must not be empty");
+    }
+    if cmd.exact && cmd.text.is_none() {
+        return ActionResult::fatal("INVALID_ARGUMENT", "--exact only applies with --text");
+    }
+
     // Validate selectors
-    if cmd.selectors.is_empty() {
+    if cmd.text.is_none() && cmd.selectors.is_empty() {
         return ActionResult::fatal("INVALID_ARGUMENT", "at least one selector required");
     }
 
@@ -217,6 +238,18 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         Err(e) => return e,
     };
 
+    if let Some(ref text) = cmd.text {
+        let selector = match find_text_target(&ctx, text, cmd.exact).await {
+            Ok(s) => s,
+            Err(e) => return e,
+        };
+        let mut result = execute_single_click(&selector, cmd, &mut ctx).await;
+        if let ActionResult::Ok { ref mut data } = result {
+            data["target"] = json!({ "text": text, "selector": selector });
+        }
+        return result;
+    }
+
     // Single selector: same response shape as before (backwards compat)
     if cmd.selectors.len() == 1 {
         return execute_single_click(&cmd.selectors[0], cmd, &mut ctx).await;
@@ -271,6 +304,164 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
     ActionResult::ok(data)
 }
 
+// ── Text targeting ─────────────────────────────────────────────────
+
+/// Build the page-side matcher for `--text`.
+///
+/// Candidates are visible elements whose label (innerText, input value,
+/// aria-label or title) contains the text, case-insensitively and with
+/// whitespace collapsed. Buttons and links beat other interactive elements,
+/// which beat plain text elements (only searched when nothing interactive
+/// matches, and only elements with text of their own); within a tier, exact
+/// matches beat partial ones. A matching element is dropped for a matching
+/// descendant of the same or a better tier, so the innermost wins unless that
+/// would trade a button for something less clickable. Each candidate carries
+/// a unique CSS path so the caller can click it — or pick one when ambiguous.
+fn text_target_js(text_json: &str, exact: bool) -> String {
+    format!(
+        r#"(function() {{
+    function norm(s) {{ return (s || '').replace(/\s+/g, ' ').trim().toLowerCase(); }}
+    var wanted = norm({text_json});
+    var exact = {exact};
+    function isVisible(el) {{
+        var rect = el.getBoundingClientRect();
+        var cs = window.getComputedStyle(el);
+        return cs.display !== 'none' && cs.visibility !== 'hidden' && rect.width > 0 && rect.height > 0;
+    }}
+    function label(el) {{
+        if (el.tagName === 'INPUT') return el.value || el.getAttribute('aria-label') || '';
+        return el.innerText || el.getAttribute('aria-label') || el.getAttribute('title') || '';
+    }}
+    function matchKind(t) {{
+        if (!t) return -1;
+        if (t === wanted) return 0;
+        return t.indexOf(wanted) !== -1 ? 1 : -1;
+    }}
+    function cssPath(el) {{
+        var parts = [];
+        while (el && el.nodeType === 1 && el !== document.documentElement) {{
+            if (el.id && document.querySelectorAll('#' + CSS.escape(el.id)).length === 1) {{
+                parts.unshift('#' + CSS.escape(el.id));
+                return parts.join(' > ');
+            }}
+            var pos = 1, sib = el.previousElementSibling;
+            while (sib) {{ if (sib.tagName === el.tagName) pos++; sib = sib.previousElementSibling; }}
+            parts.unshift(el.tagName.toLowerCase() + ':nth-of-type(' + pos + ')');
+            el = el.parentElement;
+        }}
+        parts.unshift('html');
+        return parts.join(' > ');
+    }}
+    var found = [];
+    function collect(sel, tier) {{
+        document.querySelectorAll(sel).forEach(function(el) {{
+            if (found.some(function(c) {{ return c.el === el; }}) || !isVisible(el)) return;
+            var kind = matchKind(norm(label(el)));
+            if (kind >= 0) found.push({{ el: el, match: kind, tier: tier }});
+        }});
+    }}
+    collect('button, a[href], [role=button], [role=link], input[type=button], input[type=submit], input[type=reset], summary', 0);
+    collect('label, [role=menuitem], [role=tab], [role=option], [role=checkbox], [role=radio], [onclick], [tabindex]', 1);
+    function hasOwnText(el) {{
+        for (var n = el.firstChild; n; n = n.nextSibling) {{
+            if (n.nodeType === 3 && /\S/.test(n.nodeValue)) return true;
+        }}
+        return false;
+    }}
+    if (!found.length) {{
+        // Only elements with their own text: reading innerText of every
+        // wrapper up the tree is quadratic on large pages.
+        document.querySelectorAll('body *').forEach(function(el) {{
+            if (!hasOwnText(el) || !isVisible(el)) return;
+            var kind = matchKind(norm(el.innerText));
+            if (kind >= 0) found.push({{ el: el, match: kind, tier: 2 }});
+        }});
+    }}
+    function describe(c) {{
+        return {{
+            selector: cssPath(c.el),
+            tag: c.el.tagName.toLowerCase(),
+            text: label(c.el).replace(/\s+/g, ' ').trim().substring(0, 80)
+        }};
+    }}
+    var near = found.filter(function(c) {{ return c.match === 1; }});
+    if (exact) found = found.filter(function(c) {{ return c.match === 0; }});
+    found = found.filter(function(c) {{
+        return !found.some(function(o) {{ return o !== c && o.tier <= c.tier && c.el.contains(o.el); }});
+    }});
+    if (!found.length) return {{ status: 'none', near: near.slice(0, 10).map(describe) }};
+    found.sort(function(a, b) {{ return (a.tier - b.tier) || (a.match - b.match); }});
+    var best = found.filter(function(c) {{ return c.match === found[0].match && c.tier === found[0].tier; }});
+    if (best.length > 1) {{
+        return {{ status: 'ambiguous', count: best.length, candidates: best.slice(0, 10).map(describe) }};
+    }}
+    var hit = describe(best[0]);
+    hit.status = 'ok';
+    return hit;
+}})()"#
+    )
+}
+
+/// Resolve `--text` to a unique CSS selector for the best-matching element.
+async fn find_text_target(
+    ctx: &TabContext,
+    text: &str,
+    exact: bool,
+) -> Result<String, ActionResult> {
+    let text_json = serde_json::to_string(text).unwrap_or_default();
+    let resp = ctx
+        .execute_on_element(
+            "Runtime.evaluate",
+            json!({
+                "expression": text_target_js(&text_json, exact),
+                "returnByValue": true,
+            }),
+        )
+        .await
+        .map_err(|e| cdp_error_to_result(e, "CDP_ERROR"))?;
+    let value = resp
+        .pointer("/result/result/value")
+        .cloned()
+        .unwrap_or(Value::Null);
+
+    match value.get("status").and_then(|v| v.as_str()) {
+        Some("ok") => value
+            .get("selector")
+            .and_then(|v| v.as_str())
+            .map(String::from)
+            .ok_or_else(|| {
+                ActionResult::fatal("INTERNAL_ERROR", "text match returned no selector")
+            }),
+        Some("ambiguous") => Err(ActionResult::fatal_with_details(
+            "MULTIPLE_MATCHES",
+            format!(
+                "{} elements match text '{text}'",
+                value.get("count").and_then(|v| v.as_u64()).unwrap_or(0)
+            ),
+            "click one of the candidate selectors, or use a longer label or --exact",
+            json!({ "text": text, "candidates": value["candidates"] }),
+        )),
+        Some("none") => {
+            let near = value.get("near").cloned().unwrap_or_else(|| json!([]));
+            let hint = if near.as_array().is_some_and(|a| !a.is_empty()) {
+                "no exact match; near matches are listed in details — drop --exact or click one of their selectors"
+            } else {
+                "check the text, or take a snapshot to see the labels on the page"
+            };
+            Err(ActionResult::fatal_with_details(
+                "ELEMENT_NOT_FOUND",
+                format!("no visible element with text '{text}'"),
+                hint,
+                json!({ "text": text, "near_matches": near }),
+            ))
+        }
+        _ => Err(ActionResult::fatal(
+            "INTERNAL_ERROR",
+            "unexpected result while matching --text",
+        )),
+    }
+}
+
 // ── Response builder ───────────────────────────────────────────────
 
 fn build_response(
@@ -0,0 +1,243 @@
+use std::time::Duration;
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::action_result::ActionResult;
+use crate::daemon::cdp_session::{cdp_error_to_result, get_cdp_and_target};
+use crate::daemon::registry::SharedRegistry;
+use crate::output::ResponseContext;
+
+const DEFAULT_TIMEOUT_MS: u64 = 30_000;
+
+/// How to resolve the dialog.
+#[derive(Clone, Debug, Serialize, Deserialize, clap::ValueEnum, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DialogAction {
+    /// Press OK (confirm/prompt return true or the prompt text)
+    Accept,
+    /// Press Cancel (confirm returns false, prompt returns null)
+    Dismiss,
+}
+
+/// Accept or dismiss a JavaScript dialog
+#[derive(Args, Debug, Clone, Serialize, Deserialize)]
+#[command(after_help = "\
+Examples:
+  actionbook browser dialog accept --session s1 --tab t1
+  actionbook browser dialog dismiss --session s1 --tab t1
+  actionbook browser dialog accept --text \"Alice\" --session s1 --tab t1
+
+Handles the alert/confirm/prompt/beforeunload dialog currently showing in the tab.
+If none is open yet, waits for the next one (up to --timeout).
+--text is the answer typed into a prompt() before accepting.
+
+Dialogs nobody is waiting for are dismissed automatically (beforeunload is
+accepted). Start the session with --auto-dismiss-dialogs false to leave them
+open for this command, or run it before the action that opens the dialog.")]
+pub struct Cmd {
+    /// accept or dismiss
+    #[arg(value_enum)]
+    pub action: DialogAction,
+    /// Text to enter into a prompt() dialog (accept only)
+    #[arg(long)]
+    #[serde(default)]
+    pub text: Option<String>,
+    /// Session ID
+    #[arg(long)]
+    #[serde(rename = "session_id")]
+    pub session: String,
+    /// Tab ID
+    #[arg(long)]
+    #[serde(rename = "tab_id")]
+    pub tab: String,
+    /// How long to wait for a dialog to open, in milliseconds (default 30000)
+    #[arg(long)]
+    #[serde(default)]
+    pub timeout: Option<u64>,
+}
+
+pub const COMMAND_NAME: &str = "browser dialog";
+
+pub fn context(cmd: &Cmd, result: &ActionResult) -> Option<ResponseContext> {
+    if let ActionResult::Fatal { code, .. } = result
+        && code == "SESSION_NOT_FOUND"
+    {
+        return None;
+    }
+    Some(ResponseContext {
+        session_id: cmd.session.clone(),
+        tab_id: Some(cmd.tab.clone()),
+        window_id: None,
+        url: None,
+        title: None,
+    })
+}
+
+/// Build `Page.handleJavaScriptDialog` params. `promptText` is only sent when
+/// accepting, since Chrome ignores it on dismiss.
+fn handle_params(cmd: &Cmd) -> Value {
+    let accept = cmd.action == DialogAction::Accept;
+    let mut params = json!({ "accept": accept });
+    if accept && let Some(ref text) = cmd.text {
+        params["promptText"] = json!(text);
+    }
+    params
+}
+
+/// Extract the dialog description from a `Page.javascriptDialogOpening` event.
+fn dialog_info(event: &Value) -> Value {
+    let p = &event["params"];
+    json!({
+        "type": p["type"].as_str().unwrap_or(""),
+        "message": p["message"].as_str().unwrap_or(""),
+        "default_prompt": p["defaultPrompt"].as_str().unwrap_or(""),
+        "url": p["url"].as_str().unwrap_or(""),
+    })
+}
+
+pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
+    if cmd.text.is_some() && cmd.action == DialogAction::Dismiss {
+        return ActionResult::fatal_with_hint(
+            "INVALID_ARGUMENT",
+            "--text only applies when accepting a dialog",
+            "use: browser dialog accept --text <answer>",
+        );
+    }
+
+    let (cdp, target_id) = match get_cdp_and_target(registry, &cmd.session, &cmd.tab).await {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    let cdp_session_id = match cdp.get_cdp_session_id(&target_id).await {
+        Some(sid) => sid,
+        None => {
+            return ActionResult::fatal(
+                "INTERNAL_ERROR",
+                format!("no CDP session for target '{target_id}'"),
+            );
+        }
+    };
+
+    // Subscribe before Page.enable: Chrome re-announces a dialog that is
+    // already open when the Page domain is enabled.
+    let mut rx = cdp
+        .subscribe_events(&cdp_session_id, "Page.javascriptDialogOpening")
+        .await;
+    if let Err(e) = cdp
+        .execute_on_tab(&target_id, "Page.enable", json!({}))
+        .await
+    {
+        return cdp_error_to_result(e, "CDP_ERROR");
+    }
+
+    let mut dialog = Value::Null;
+    while let Ok(ev) = rx.try_recv() {
+        dialog = dialog_info(&ev);
+    }
+
+    let params = handle_params(cmd);
+
+    // Fast path: a dialog is already showing.
+    match cdp
+        .execute_on_tab(&target_id, "Page.handleJavaScriptDialog", params.clone())
+        .await
+    {
+        Ok(_) => {
+            return ActionResult::ok(json!({
+                "action": "dialog",
+                "accepted": cmd.action == DialogAction::Accept,
+                "dialog": dialog,
+            }));
+        }
+        Err(e) if !e.to_string().contains("No dialog is showing") => {
+            return cdp_error_to_result(e, "CDP_ERROR");
+        }
+        Err(_) => {}
+    }
+
+    // Otherwise wait for the next one to open.
+    let timeout_ms = cmd.timeout.unwrap_or(DEFAULT_TIMEOUT_MS);
+    let event = match tokio::time::timeout(Duration::from_millis(timeout_ms), rx.recv()).await {
+        Ok(Some(ev)) => ev,
+        Ok(None) => {
+            return ActionResult::fatal("CDP_ERROR", "tab session closed while waiting for dialog");
+        }
+        Err(_) => {
+            return ActionResult::fatal_with_hint(
+                "TIMEOUT",
+                format!("no dialog opened within {timeout_ms}ms"),
+                "trigger the dialog (e.g. click) after starting this command, or increase --timeout",
+            );
+        }
+    };
+
+    if let Err(e) = cdp
+        .execute_on_tab(&target_id, "Page.handleJavaScriptDialog", params)
+        .await
+    {
+        return cdp_error_to_result(e, "CDP_ERROR");
+    }
+
+    ActionResult::ok(json!({
+        "action": "dialog",
+        "accepted": cmd.action == DialogAction::Accept,
+        "dialog": dialog_info(&event),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cmd(action: DialogAction, text: Option<&str>) -> Cmd {
+        Cmd {
+            action,
+            text: text.map(String::from),
+            session: "s1".to_string(),
+            tab: "t1".to_string(),
+            timeout: None,
+        }
+    }
+
+    #[test]
+    fn handle_params_passes_prompt_text_only_on_accept() {
+        assert_eq!(
+            handle_params(&cmd(DialogAction::Accept, Some("Alice"))),
+            json!({ "accept": true, "promptText": "Alice" })
+        );
+        assert_eq!(
+            handle_params(&cmd(DialogAction::Accept, None)),
+            json!({ "accept": true })
+        );
+        assert_eq!(
+            handle_params(&cmd(DialogAction::Dismiss, None)),
+            json!({ "accept": false })
+        );
+    }
+
+    #[test]
+    fn dialog_info_reads_event_params() {
+        let ev = json!({
+            "method": "Page.javascriptDialogOpening",
+            "params": {
+                "url": "https://example.com/",
+                "message": "Name?",
+                "type": "prompt",
+                "defaultPrompt": "Bob",
+                "hasBrowserHandler": false
+            }
+        });
+        assert_eq!(
+            dialog_info(&ev),
+            json!({
+                "type": "prompt",
+                "message": "Name?",
+                "default_prompt": "Bob",
+                "url": "https://example.com/",
+            })
+        );
+    }
+}
@@ -1,6 +1,7 @@
 pub mod batch_click;
 pub mod click;
 pub mod cursor_position;
+pub mod dialog;
 pub mod drag;
 pub mod eval;
 pub mod fill;
@@ -4,7 +4,7 @@ use serde_json::json;
 
 use crate::action_result::ActionResult;
 use crate::browser::navigation;
-use crate::daemon::cdp_session::{cdp_error_to_result, get_cdp_and_target};
+use crate::daemon::cdp_session::{CdpSession, cdp_error_to_result, get_cdp_and_target};
 use crate::daemon::registry::SharedRegistry;
 use crate::output::ResponseContext;
 
@@ -16,12 +16,14 @@ Examples:
   actionbook browser press Tab --session s1 --tab t1
   actionbook browser press Control+A --session s1 --tab t1
   actionbook browser press Shift+Tab --session s1 --tab t1
+  actionbook browser press \"Tab Tab Enter\" --session s1 --tab t1
 
 Sends a key press to the currently focused element.
+Separate keys or chords with spaces to press them in sequence.
 Use focus first to direct keys to a specific element.
 Key names follow CDP conventions: Enter, Tab, Escape, ArrowDown, Control, Shift, Alt, Meta.")]
 pub struct Cmd {
-    /// Single key or key combination (e.g., Enter, Control+A, Shift+Tab)
+    /// Key, key combination, or space-separated sequence (e.g., Enter, Control+A, "Tab Enter")
     pub key: String,
     /// Session ID
     #[arg(long)]
@@ -111,6 +113,27 @@ fn parse_chord(input: &str) -> Result<(u32, String), ActionResult> {
     Ok((modifiers, main_key))
 }
 
+/// Parse a space-separated sequence of keys or chords, in press order.
+fn parse_sequence(input: &str) -> Result<Vec<(u32, String)>, ActionResult> {
+    // Tighten spaces around '+' first so "Control + A" stays one chord.
+    let tightened = input
+        .split('+')
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("+");
+    let chords = tightened
+        .split_whitespace()
+        .map(parse_chord)
+        .collect::<Result<Vec<_>, _>>()?;
+    if chords.is_empty() {
+        return Err(ActionResult::fatal(
+            "INVALID_ARGUMENT",
+            "no key given — pass a key name such as Enter or Control+A",
+        ));
+    }
+    Ok(chords)
+}
+
 /// Map a key name to the CDP `key` value.
 /// Single printable characters with modifiers are lowercased.
 fn cdp_key(key: &str, has_modifiers: bool) -> String {
@@ -268,15 +291,12 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         Err(e) => return e,
     };
 
-    // Parse the key-or-chord
-    let (modifiers, main_key) = match parse_chord(&cmd.key) {
+    // Parse the key, chord, or sequence
+    let chords = match parse_sequence(&cmd.key) {
         Ok(v) => v,
         Err(e) => return e,
     };
 
-    let key = cdp_key(&main_key, modifiers != 0);
-    let def = key_definition(&key);
-
     // Chrome routes Input.dispatchKeyEvent to the active (foreground) tab,
     // ignoring the CDP sessionId.  Activate our target tab first so key
     // events reach the right page.  Matches Playwright's Page.bringToFront().
@@ -284,6 +304,37 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         .execute_browser("Target.activateTarget", json!({ "targetId": target_id }))
         .await;
 
+    for (modifiers, main_key) in &chords {
+        if let Err(e) = dispatch_chord(&cdp, &target_id, *modifiers, main_key).await {
+            return e;
+        }
+    }
+
+    let url = navigation::get_tab_url(&cdp, &target_id).await;
+    let title = navigation::get_tab_title(&cdp, &target_id).await;
+
+    ActionResult::ok(json!({
+        "action": "press",
+        "keys": cmd.key,
+        "changed": {
+            "url_changed": false,
+            "focus_changed": false,
+        },
+        "post_url": url,
+        "post_title": title,
+    }))
+}
+
+/// Send one keyDown/keyUp pair for a parsed chord.
+async fn dispatch_chord(
+    cdp: &CdpSession,
+    target_id: &str,
+    modifiers: u32,
+    main_key: &str,
+) -> Result<(), ActionResult> {
+    let key = cdp_key(main_key, modifiers != 0);
+    let def = key_definition(&key);
+
     // Chrome CDP dispatches key events with specific type semantics:
     //   - "keyDown" with `text`: generates both keydown + keypress DOM events
     //     and triggers native behaviours (form submit, focus switch, etc.)
@@ -323,12 +374,9 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         key_down["unmodifiedText"] = json!(text);
     }
 
-    if let Err(e) = cdp
-        .execute_on_tab(&target_id, "Input.dispatchKeyEvent", key_down)
+    cdp.execute_on_tab(target_id, "Input.dispatchKeyEvent", key_down)
         .await
-    {
-        return cdp_error_to_result(e, "CDP_ERROR");
-    }
+        .map_err(|e| cdp_error_to_result(e, "CDP_ERROR"))?;
 
     // keyUp
     let mut key_up = json!({
@@ -342,24 +390,44 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         key_up["nativeVirtualKeyCode"] = json!(d.key_code);
     }
 
-    if let Err(e) = cdp
-        .execute_on_tab(&target_id, "Input.dispatchKeyEvent", key_up)
+    cdp.execute_on_tab(target_id, "Input.dispatchKeyEvent", key_up)
         .await
-    {
-        return cdp_error_to_result(e, "CDP_ERROR");
-    }
+        .map_err(|e| cdp_error_to_result(e, "CDP_ERROR"))?;
 
-    let url = navigation::get_tab_url(&cdp, &target_id).await;
-    let title = navigation::get_tab_title(&cdp, &target_id).await;
+    Ok(())
+}
 
-    ActionResult::ok(json!({
-        "action": "press",
-        "keys": cmd.key,
-        "changed": {
-            "url_changed": false,
-            "focus_changed": false,
-        },
-        "post_url": url,
-        "post_title": title,
-    }))
+#[cfg(test)]
+mod tests {
+    use super::{parse_chord, parse_sequence};
+
+    #[test]
+    fn parse_chord_maps_modifiers_to_cdp_bitmask() {
+        assert_eq!(parse_chord("Enter").ok(), Some((0, "Enter".to_string())));
+        assert_eq!(parse_chord("Control+A").ok(), Some((2, "A".to_string())));
+        assert_eq!(
+            parse_chord("Meta+Shift+K").ok(),
+            Some((4 | 8, "K".to_string()))
+        );
+        assert!(parse_chord("Hyper+K").is_err());
+        assert!(parse_chord("Shift+").is_err());
+    }
+
+    #[test]
+    fn parse_sequence_splits_on_whitespace() {
+        assert_eq!(
+            parse_sequence("Tab  Shift+Tab Enter").ok(),
+            Some(vec![
+                (0, "Tab".to_string()),
+                (8, "Tab".to_string()),
+                (0, "Enter".to_string()),
+            ])
+        );
+        assert_eq!(
+            parse_sequence("Control + A").ok(),
+            Some(vec![(2, "A".to_string())])
+        );
+        assert!(parse_sequence("   ").is_err());
+        assert!(parse_sequence("Tab Bogus+X").is_err());
+    }
 }
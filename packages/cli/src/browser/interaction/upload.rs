@@ -2,7 +2,7 @@ use std::path::Path;
 
 use clap::Args;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde_json::{Value, json};
 
 use crate::action_result::ActionResult;
 use crate::browser::element::TabContext;
@@ -10,6 +10,7 @@ use crate::browser::navigation;
 use crate::daemon::cdp_session::cdp_error_to_result;
 use crate::daemon::registry::SharedRegistry;
 use crate::output::ResponseContext;
+use crate::types::Mode;
 
 /// Upload files to a file input
 #[derive(Args, Debug, Clone, Serialize, Deserialize)]
@@ -78,6 +79,19 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         }
     }
 
+    // Cloud browsers read paths on the remote host, so only check local files
+    // for local and extension sessions.
+    let mode = registry.lock().await.get(&cmd.session).map(|e| e.mode);
+    if mode.is_some_and(|m| m != Mode::Cloud)
+        && let Some(missing) = cmd.files.iter().find(|f| !Path::new(f).is_file())
+    {
+        return ActionResult::fatal_with_hint(
+            "INVALID_ARGUMENT",
+            format!("file not found: '{missing}'"),
+            "check the path exists and points to a regular file",
+        );
+    }
+
     // Get CDP session and verify tab
     let mut ctx = match TabContext::new(registry, &cmd.session, &cmd.tab).await {
         Ok(v) => v,
@@ -90,6 +104,22 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         Err(e) => return e,
     };
 
+    // Reject non file inputs up front; CDP's own error here is opaque.
+    let described = match ctx
+        .execute_on_element("DOM.describeNode", json!({ "nodeId": node_id }))
+        .await
+    {
+        Ok(v) => v,
+        Err(e) => return cdp_error_to_result(e, "CDP_ERROR"),
+    };
+    if !is_file_input(&described["result"]["node"]) {
+        return ActionResult::fatal_with_hint(
+            "INVALID_ARGUMENT",
+            format!("'{}' is not an <input type=\"file\"> element", cmd.selector),
+            "target the file input itself, not its label or a styled wrapper",
+        );
+    }
+
     // Set files on the input via DOM.setFileInputFiles
     if let Err(e) = ctx
         .execute_on_element(
@@ -118,3 +148,50 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         "post_title": title,
     }))
 }
+
+/// Whether a `DOM.describeNode` node is an `<input type="file">`.
+fn is_file_input(node: &Value) -> bool {
+    if !node["localName"]
+        .as_str()
+        .is_some_and(|n| n.eq_ignore_ascii_case("input"))
+    {
+        return false;
+    }
+    // `attributes` is a flat [name, value, name, value, ...] list.
+    node["attributes"].as_array().is_some_and(|attrs| {
+        attrs.chunks(2).any(|pair| {
+            pair[0]
+                .as_str()
+                .is_some_and(|n| n.eq_ignore_ascii_case("type"))
+                && pair
+                    .get(1)
+                    .and_then(|v| v.as_str())
+                    .is_some_and(|v| v.trim().eq_ignore_ascii_case("file"))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_file_input;
+    use serde_json::json;
+
+    #[test]
+    fn is_file_input_matches_type_file_inputs_only() {
+        assert!(is_file_input(&json!({
+            "localName": "input",
+            "attributes": ["id", "avatar", "type", "File"],
+        })));
+        assert!(!is_file_input(&json!({
+            "localName": "input",
+            "attributes": ["type", "text"],
+        })));
+        assert!(!is_file_input(
+            &json!({ "localName": "input", "attributes": [] })
+        ));
+        assert!(!is_file_input(&json!({
+            "localName": "label",
+            "attributes": ["type", "file"],
+        })));
+    }
+}
@@ -1,8 +1,11 @@
 pub mod cookies;
 pub mod element;
+pub mod emulation;
 pub mod interaction;
 pub mod navigation;
 pub mod observation;
+pub mod profiles;
+pub mod schema;
 pub mod session;
 pub mod stealth;
 pub mod storage;
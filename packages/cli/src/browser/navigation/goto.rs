@@ -1,6 +1,6 @@
 use clap::Args;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde_json::{Value, json};
 
 use crate::action_result::ActionResult;
 use crate::daemon::cdp::ensure_scheme_or_fatal;
@@ -31,6 +31,8 @@ Examples:
 
 A scheme (https://) is added automatically if omitted.
 After navigation, context.url and context.title are updated.
+data.response carries the main document's HTTP status and headers
+(null for about:blank, same-document, or not-yet-answered navigations).
 
 --wait-until controls when the command returns:
   domcontentloaded (default) — wait for DOMContentLoaded (DOM ready, faster)
@@ -93,6 +95,7 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
 
     // Get from_url before navigation
     let from_url = super::get_tab_url(&cdp, &target_id).await;
+    let mut response = Value::Null;
 
     if !target_id.is_empty() {
         // Determine which CDP event to wait for (if any).
@@ -119,6 +122,11 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         } else {
             None
         };
+        // Network is enabled at attach time, so the document response for
+        // this navigation arrives here without any extra domain setup.
+        let mut response_rx = cdp
+            .subscribe_events(&cdp_session_id, "Network.responseReceived")
+            .await;
 
         // Page.enable is idempotent — safe to call on every goto.
         // Required for Page.domContentEventFired / Page.loadEventFired events.
@@ -142,7 +150,7 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
             while rx.try_recv().is_ok() {}
         }
 
-        match cdp
+        let loader_id = match cdp
             .execute_on_tab(&target_id, "Page.navigate", json!({ "url": final_url }))
             .await
         {
@@ -153,8 +161,10 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
                 {
                     return ActionResult::fatal("NAVIGATION_FAILED", err_text.to_string());
                 }
+                // Absent for same-document (fragment) navigations.
+                v["result"]["loaderId"].as_str().map(String::from)
             }
-        }
+        };
 
         // Wait for the subscribed CDP event.
         // No internal timeout — the global --timeout flag (set in main.rs) controls
@@ -164,6 +174,17 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         if let Some(ref mut rx) = event_rx {
             let _ = rx.recv().await; // None = channel closed (session died), proceed best-effort
         }
+
+        // The document response always precedes DOMContentLoaded, so by now
+        // it is already queued. With --wait-until none it may not have
+        // arrived yet; report null rather than blocking.
+        if let Some(loader_id) = loader_id {
+            let mut events = Vec::new();
+            while let Ok(ev) = response_rx.try_recv() {
+                events.push(ev);
+            }
+            response = document_response(&events, &loader_id);
+        }
     }
 
     // Get to_url and title after navigation (+ wait)
@@ -193,5 +214,76 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         "from_url": from_url,
         "to_url": to_url,
         "title": title,
+        "response": response,
     }))
 }
+
+/// Pick the main document's response for `loader_id` out of buffered
+/// `Network.responseReceived` events. Redirect hops never produce a
+/// `responseReceived`, so the match is the final response.
+fn document_response(events: &[Value], loader_id: &str) -> Value {
+    events
+        .iter()
+        .rev()
+        .filter_map(|ev| ev.get("params"))
+        .find(|p| p["type"] == "Document" && p["loaderId"] == loader_id)
+        .map(|p| {
+            let r = &p["response"];
+            json!({
+                "status": r["status"],
+                "status_text": r["statusText"],
+                "url": r["url"],
+                "mime_type": r["mimeType"],
+                "headers": r["headers"],
+            })
+        })
+        .unwrap_or(Value::Null)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response_event(loader_id: &str, kind: &str, status: u16, url: &str) -> Value {
+        json!({
+            "method": "Network.responseReceived",
+            "params": {
+                "loaderId": loader_id,
+                "type": kind,
+                "response": {
+                    "status": status,
+                    "statusText": "",
+                    "url": url,
+                    "mimeType": "text/html",
+                    "headers": { "content-type": "text/html" },
+                },
+            },
+        })
+    }
+
+    #[test]
+    fn document_response_matches_loader_and_document_type() {
+        let events = vec![
+            response_event("OLD", "Document", 200, "https://old.example/"),
+            response_event("L1", "Script", 200, "https://cdn.example/app.js"),
+            response_event("L1", "Document", 404, "https://example.com/missing"),
+        ];
+
+        let response = document_response(&events, "L1");
+        assert_eq!(response["status"], 404);
+        assert_eq!(response["url"], "https://example.com/missing");
+        assert_eq!(response["headers"]["content-type"], "text/html");
+    }
+
+    #[test]
+    fn document_response_is_null_without_a_match() {
+        let events = vec![response_event(
+            "OLD",
+            "Document",
+            200,
+            "https://old.example/",
+        )];
+        assert!(document_response(&events, "L1").is_null());
+        assert!(document_response(&[], "L1").is_null());
+    }
+}
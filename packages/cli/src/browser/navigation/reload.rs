@@ -1,3 +1,4 @@
+use clap::Args;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
@@ -7,14 +8,27 @@ use crate::daemon::registry::SharedRegistry;
 use crate::output::ResponseContext;
 
 /// Reload the current page
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Args, Debug, Clone, Serialize, Deserialize)]
+#[command(after_help = "\
+Examples:
+  actionbook browser reload --session s1 --tab t1
+  actionbook browser reload --hard --session s1 --tab t1
+
+--hard bypasses the HTTP cache (like Shift+Reload), forcing every
+resource to be fetched fresh from the server.")]
 pub struct Cmd {
     /// Session ID
+    #[arg(long)]
     #[serde(rename = "session_id")]
     pub session: String,
     /// Tab ID
+    #[arg(long)]
     #[serde(rename = "tab_id")]
     pub tab: String,
+    /// Bypass the HTTP cache
+    #[arg(long)]
+    #[serde(default)]
+    pub hard: bool,
 }
 
 pub const COMMAND_NAME: &str = "browser reload";
@@ -55,7 +69,11 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
     let title = super::get_tab_title(&cdp, &target_id).await;
 
     if let Err(e) = cdp
-        .execute_on_tab(&target_id, "Page.reload", json!({}))
+        .execute_on_tab(
+            &target_id,
+            "Page.reload",
+            json!({ "ignoreCache": cmd.hard }),
+        )
         .await
     {
         return ActionResult::fatal("NAVIGATION_FAILED", e.to_string());
@@ -73,5 +91,6 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         "from_url": url,
         "to_url": url,
         "title": title,
+        "hard": cmd.hard,
     }))
 }
@@ -8,15 +8,19 @@ use crate::daemon::cdp_session::cdp_error_to_result;
 use crate::daemon::registry::SharedRegistry;
 use crate::output::ResponseContext;
 
-/// Read a named attribute from an element
+/// Read a named attribute (or live DOM property) from an element
 #[derive(Args, Debug, Clone, Serialize, Deserialize)]
 #[command(after_help = "\
 Examples:
   actionbook browser attr \"a.link\" href --session s1 --tab t1
   actionbook browser attr @e3 aria-label --session s1 --tab t1
   actionbook browser attr \"img\" src --session s1 --tab t1
+  actionbook browser attr \"#agree\" checked --property --session s1 --tab t1
 
-Accepts a CSS selector, XPath, or snapshot ref (@eN from snapshot output).")]
+Accepts a CSS selector, XPath, or snapshot ref (@eN from snapshot output).
+Returns null when the attribute is absent; a missing element is an error.
+--property reads the live DOM property instead (e.g. the current value of an
+input after typing, or checked as true/false).")]
 pub struct Cmd {
     /// Selector (CSS, XPath, or @ref)
     pub selector: String,
@@ -30,6 +34,10 @@ pub struct Cmd {
     #[arg(long)]
     #[serde(rename = "tab_id")]
     pub tab: String,
+    /// Read the live DOM property instead of the HTML attribute
+    #[arg(long)]
+    #[serde(default)]
+    pub property: bool,
 }
 
 pub const COMMAND_NAME: &str = "browser attr";
@@ -73,7 +81,7 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         Err(e) => return e,
     };
 
-    let value = match get_attr(&mut ctx, &cmd.selector, &cmd.name).await {
+    let value = match get_attr(&mut ctx, &cmd.selector, &cmd.name, cmd.property).await {
         Ok(v) => v,
         Err(e) => return e,
     };
@@ -92,12 +100,19 @@ async fn get_attr(
     ctx: &mut TabContext,
     selector: &str,
     attr_name: &str,
+    property: bool,
 ) -> Result<Value, ActionResult> {
     let (_, object_id) = ctx.resolve_object(selector).await?;
     let attr_json = serde_json::to_string(attr_name).map_err(|e| {
         ActionResult::fatal("INTERNAL_ERROR", format!("serialize attribute name: {e}"))
     })?;
-    let function = format!(r#"function() {{ return this.getAttribute({attr_json}); }}"#);
+    // Properties holding DOM objects (e.g. `form`) serialize as {} rather than
+    // failing; `undefined` comes back without a value and maps to null.
+    let function = if property {
+        format!(r#"function() {{ return this[{attr_json}]; }}"#)
+    } else {
+        format!(r#"function() {{ return this.getAttribute({attr_json}); }}"#)
+    };
 
     let resp = ctx
         .execute_on_element(
@@ -47,6 +47,10 @@ pub struct Cmd {
     #[arg(long, short = 's')]
     #[serde(default)]
     pub selector: Option<String>,
+    /// Include only nodes with these roles (comma-separated or repeated)
+    #[arg(long, value_delimiter = ',')]
+    #[serde(default)]
+    pub role: Vec<String>,
 }
 
 pub const COMMAND_NAME: &str = "browser batch-snapshot";
@@ -79,6 +83,8 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
             cursor: cmd.cursor,
             depth: cmd.depth,
             selector: cmd.selector.clone(),
+            role: cmd.role.clone(),
+            diff: false,
         };
         match snapshot::execute(&tab_cmd, registry).await {
             ActionResult::Ok { data } => {
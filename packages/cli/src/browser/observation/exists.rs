@@ -0,0 +1,100 @@
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::action_result::ActionResult;
+use crate::browser::{element::TabContext, navigation};
+use crate::daemon::registry::SharedRegistry;
+use crate::output::ResponseContext;
+
+/// Check whether an element exists (no waiting)
+#[derive(Args, Debug, Clone, Serialize, Deserialize)]
+#[command(after_help = "\
+Examples:
+  actionbook browser exists \"#cookie-banner\" --session s1 --tab t1
+  actionbook browser exists \"#cookie-banner\" --session s1 --tab t1 && actionbook browser click \"#accept\" --session s1 --tab t1
+
+Accepts a CSS selector, XPath, or snapshot ref (@eN from snapshot output).
+Checks once and prints true or false; use `wait element` to block until it appears.
+Exit codes: 0 when the element exists, 1 when it does not, 2 on error.")]
+pub struct Cmd {
+    /// Selector (CSS, XPath, or @ref)
+    pub selector: String,
+    /// Session ID
+    #[arg(long)]
+    #[serde(rename = "session_id")]
+    pub session: String,
+    /// Tab ID
+    #[arg(long)]
+    #[serde(rename = "tab_id")]
+    pub tab: String,
+}
+
+pub const COMMAND_NAME: &str = "browser exists";
+
+pub fn context(cmd: &Cmd, result: &ActionResult) -> Option<ResponseContext> {
+    if let ActionResult::Fatal { code, .. } = result
+        && code == "SESSION_NOT_FOUND"
+    {
+        return None;
+    }
+    let tab_id = if let ActionResult::Fatal { code, .. } = result
+        && code == "TAB_NOT_FOUND"
+    {
+        None
+    } else {
+        Some(cmd.tab.clone())
+    };
+    let (url, title) = match result {
+        ActionResult::Ok { data } => (
+            data.get("__ctx_url")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+            data.get("__ctx_title")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+        ),
+        _ => (None, None),
+    };
+    Some(ResponseContext {
+        session_id: cmd.session.clone(),
+        tab_id,
+        window_id: None,
+        url,
+        title,
+    })
+}
+
+/// Resolve `selector` once. `Ok(None)` means the selector is valid but
+/// matches nothing; invalid selectors and stale refs stay errors.
+pub(crate) async fn find_node(
+    ctx: &mut TabContext,
+    selector: &str,
+) -> Result<Option<i64>, ActionResult> {
+    match ctx.resolve_node(selector).await {
+        Ok(node_id) => Ok(Some(node_id)),
+        Err(ActionResult::Fatal { ref code, .. }) if code == "ELEMENT_NOT_FOUND" => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
+    let mut ctx = match TabContext::new(registry, &cmd.session, &cmd.tab).await {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    let exists = match find_node(&mut ctx, &cmd.selector).await {
+        Ok(node) => node.is_some(),
+        Err(e) => return e,
+    };
+    let url = navigation::get_tab_url(&ctx.cdp, &ctx.target_id).await;
+    let title = navigation::get_tab_title(&ctx.cdp, &ctx.target_id).await;
+
+    ActionResult::ok(json!({
+        "target": { "selector": cmd.selector },
+        "value": exists,
+        "__ctx_url": url,
+        "__ctx_title": title,
+    }))
+}
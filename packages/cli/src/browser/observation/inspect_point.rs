@@ -3,11 +3,12 @@ use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
 
 use crate::action_result::ActionResult;
+use crate::browser::element::{self, TabContext};
 use crate::daemon::cdp_session::{CdpSession, cdp_error_to_result, get_cdp_and_target};
 use crate::daemon::registry::SharedRegistry;
 use crate::output::ResponseContext;
 
-use super::snapshot_transform::RefCache;
+use super::snapshot_transform::{ElementState, RefCache};
 
 /// Inspect the element at specified coordinates
 #[derive(Args, Debug, Clone, Serialize, Deserialize)]
@@ -15,13 +16,21 @@ use super::snapshot_transform::RefCache;
 Examples:
   actionbook browser inspect-point 420,310 --session s1 --tab t1
   actionbook browser inspect-point 420,310 --parent-depth 2 --session s1 --tab t1
+  actionbook browser inspect-point --selector \"#submit\" --session s1 --tab t1
 
 Returns the element's role, name, and CSS selector at the given x,y point.
-Use with screenshot to identify elements visually.")]
+Use with screenshot to identify elements visually.
+With --selector (CSS, XPath, or @ref), inspects the first matching element
+instead; point is then the element's centre in viewport coordinates.")]
 pub struct Cmd {
     /// Point to inspect as "x,y" (e.g. "100,200")
-    #[arg(allow_hyphen_values = true)]
-    pub coordinates: String,
+    #[arg(allow_hyphen_values = true, required_unless_present = "selector")]
+    #[serde(default)]
+    pub coordinates: Option<String>,
+    /// Inspect the element matching this selector instead of a point
+    #[arg(long, conflicts_with = "coordinates")]
+    #[serde(default)]
+    pub selector: Option<String>,
     /// Session ID
     #[arg(long)]
     #[serde(rename = "session_id")]
@@ -37,6 +46,47 @@ pub struct Cmd {
 
 pub const COMMAND_NAME: &str = "browser inspect-point";
 
+/// Role, accessible name and `@ref` selector of an inspected node. Used for
+/// both the hit element and its parents; parents never carry state flags.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InspectedNode {
+    pub role: String,
+    pub name: String,
+    pub selector: String,
+    #[serde(flatten)]
+    pub state: ElementState,
+}
+
+/// Viewport coordinates of the inspected point.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// The stable part of an `inspect-point` payload. `element` is `null` when
+/// nothing is under the point; `point` is `null` for a `--selector` target
+/// without a layout box.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InspectResult {
+    pub point: Option<Point>,
+    pub element: Option<InspectedNode>,
+    #[serde(default)]
+    pub parents: Vec<InspectedNode>,
+}
+
+impl InspectResult {
+    /// Serialize into the response payload, adding command-specific extras
+    /// (`target`, `__ctx_url`) alongside the typed fields.
+    fn into_data(self, extra: Value) -> Value {
+        let mut data = serde_json::to_value(self).unwrap_or_else(|_| json!({}));
+        if let (Some(obj), Value::Object(extra)) = (data.as_object_mut(), extra) {
+            obj.extend(extra);
+        }
+        data
+    }
+}
+
 /// Parse coordinate string "x,y" into (f64, f64).
 pub fn parse_coordinates(coords: &str) -> Result<(f64, f64), String> {
     let parts: Vec<&str> = coords.splitn(2, ',').collect();
@@ -87,8 +137,19 @@ pub fn context(cmd: &Cmd, result: &ActionResult) -> Option<ResponseContext> {
 }
 
 pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
+    if let Some(selector) = cmd.selector.as_deref() {
+        return execute_selector(cmd, selector, registry).await;
+    }
+
     // Validate coordinates early
-    let (x, y) = match parse_coordinates(&cmd.coordinates) {
+    let Some(coordinates) = cmd.coordinates.as_deref() else {
+        return ActionResult::fatal_with_hint(
+            "INVALID_ARGUMENT",
+            "inspect-point needs coordinates or --selector",
+            "pass x,y (e.g. 420,310) or --selector <sel>",
+        );
+    };
+    let (x, y) = match parse_coordinates(coordinates) {
         Ok(v) => v,
         Err(e) => return ActionResult::fatal("INVALID_ARGUMENT", e),
     };
@@ -115,19 +176,90 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
     }
 
     match result {
-        Ok((element, parents)) => ActionResult::ok(json!({
-            "point": { "x": x, "y": y },
-            "element": element,
-            "parents": parents,
-            "__ctx_url": url,
-        })),
+        Ok((element, parents)) => ActionResult::ok(
+            InspectResult {
+                point: Some(Point { x, y }),
+                element,
+                parents,
+            }
+            .into_data(json!({ "__ctx_url": url })),
+        ),
+        Err(e) => e,
+    }
+}
+
+/// `--selector` mode: resolve the element, then report the same payload as a
+/// hit-test would, with `point` set to the element's centre.
+async fn execute_selector(cmd: &Cmd, selector: &str, registry: &SharedRegistry) -> ActionResult {
+    let mut ctx = match TabContext::new(registry, &cmd.session, &cmd.tab).await {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let node_id = match ctx.resolve_node(selector).await {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let backend_node_id = match ctx
+        .execute_on_element("DOM.describeNode", json!({ "nodeId": node_id }))
+        .await
+    {
+        Ok(v) => match v
+            .pointer("/result/node/backendNodeId")
+            .and_then(|v| v.as_i64())
+        {
+            Some(id) => id,
+            None => return element::element_not_found(selector),
+        },
+        Err(e) => return cdp_error_to_result(e, "CDP_ERROR"),
+    };
+    // Zero-size or detached elements have no box; still inspect them.
+    let frame_id = ctx.resolved_frame_id().map(String::from);
+    let point = ctx
+        .get_center(node_id, selector, frame_id.as_deref())
+        .await
+        .ok()
+        .map(|(x, y)| Point { x, y });
+
+    let url = crate::browser::navigation::get_tab_url(&ctx.cdp, &ctx.target_id).await;
+
+    let mut ref_cache = {
+        let mut reg = registry.lock().await;
+        reg.take_ref_cache(&cmd.session, &cmd.tab)
+    };
+
+    let result = inspect_backend_node(
+        &ctx.cdp,
+        &ctx.target_id,
+        backend_node_id,
+        cmd.parent_depth,
+        &mut ref_cache,
+    )
+    .await;
+
+    {
+        let mut reg = registry.lock().await;
+        reg.put_ref_cache(&cmd.session, &cmd.tab, ref_cache);
+    }
+
+    match result {
+        Ok((element, parents)) => ActionResult::ok(
+            InspectResult {
+                point,
+                element: Some(element),
+                parents,
+            }
+            .into_data(json!({
+                "target": { "selector": selector },
+                "__ctx_url": url,
+            })),
+        ),
         Err(e) => e,
     }
 }
 
 /// Hit-test at (x, y) and return (element, parents).
 ///
-/// Returns `Ok((null, []))` when no element is at the point.
+/// Returns `Ok((None, []))` when no element is at the point.
 async fn inspect_at_point(
     cdp: &CdpSession,
     target_id: &str,
@@ -135,7 +267,7 @@ async fn inspect_at_point(
     y: f64,
     parent_depth: Option<u32>,
     ref_cache: &mut RefCache,
-) -> Result<(Value, Value), ActionResult> {
+) -> Result<(Option<InspectedNode>, Vec<InspectedNode>), ActionResult> {
     // Use DOM.getNodeForLocation to find the element at (x, y).
     // Coordinates must be integers for CDP.
     let hit = cdp
@@ -158,35 +290,44 @@ async fn inspect_at_point(
 
     let Some(backend_node_id) = backend_node_id else {
         // No element at coordinates — return null element
-        return Ok((Value::Null, json!([])));
+        return Ok((None, Vec::new()));
     };
 
+    let (element, parents) =
+        inspect_backend_node(cdp, target_id, backend_node_id, parent_depth, ref_cache).await?;
+    Ok((Some(element), parents))
+}
+
+/// Return (element, parents) AX info for a resolved backend node.
+async fn inspect_backend_node(
+    cdp: &CdpSession,
+    target_id: &str,
+    backend_node_id: i64,
+    parent_depth: Option<u32>,
+    ref_cache: &mut RefCache,
+) -> Result<(InspectedNode, Vec<InspectedNode>), ActionResult> {
     // Get AX info for the element
     let element_info =
         get_ax_info_for_backend_node(cdp, target_id, backend_node_id, ref_cache).await?;
 
     // Collect parents if requested
-    let parents = if let Some(depth) = parent_depth {
-        if depth > 0 {
-            collect_parents(cdp, target_id, backend_node_id, depth, ref_cache).await?
-        } else {
-            json!([])
+    let parents = match parent_depth {
+        Some(depth) if depth > 0 => {
+            collect_parents(cdp, target_id, backend_node_id, depth, ref_cache).await
         }
-    } else {
-        json!([])
+        _ => Vec::new(),
     };
 
     Ok((element_info, parents))
 }
 
-/// Get AX role/name/selector for a backend node ID.
-/// Returns a JSON object {role, name, selector}.
+/// Get AX role/name/selector and state flags for a backend node ID.
 async fn get_ax_info_for_backend_node(
     cdp: &CdpSession,
     target_id: &str,
     backend_node_id: i64,
     ref_cache: &mut RefCache,
-) -> Result<Value, ActionResult> {
+) -> Result<InspectedNode, ActionResult> {
     let ax_resp = cdp
         .execute_on_tab(
             target_id,
@@ -203,29 +344,35 @@ async fn get_ax_info_for_backend_node(
         .as_array()
         .and_then(|arr| arr.first());
 
-    let (role, name) = if let Some(node) = nodes {
+    let (role, name, state) = if let Some(node) = nodes {
         let role = node["role"]["value"]
             .as_str()
             .unwrap_or("generic")
             .to_string();
         let name = node["name"]["value"].as_str().unwrap_or("").to_string();
-        (role, name)
+        let state = ElementState::from_ax_node(&role, node);
+        (role, name, state)
     } else {
-        ("generic".to_string(), String::new())
+        (
+            "generic".to_string(),
+            String::new(),
+            ElementState::default(),
+        )
     };
 
     // Assign stable ref from RefCache
     let selector = ref_cache.get_or_assign(backend_node_id, &role, &name, None);
 
-    Ok(json!({
-        "role": role,
-        "name": name,
-        "selector": selector,
-    }))
+    Ok(InspectedNode {
+        role,
+        name,
+        selector,
+        state,
+    })
 }
 
-/// Walk up the AX parent chain, collecting up to `depth` ancestors.
-/// Returns a JSON array of {role, name, selector} objects, nearest parent first.
+/// Walk up the AX parent chain, collecting up to `depth` ancestors,
+/// nearest parent first. CDP failures yield an empty list.
 ///
 /// Uses `Accessibility.getPartialAXTree` with `fetchRelatives: true` to get
 /// the element and all its AX ancestors in a single CDP call, then walks up
@@ -236,7 +383,7 @@ async fn collect_parents(
     backend_node_id: i64,
     depth: u32,
     ref_cache: &mut RefCache,
-) -> Result<Value, ActionResult> {
+) -> Vec<InspectedNode> {
     // Fetch the AX tree including ancestors.
     let ax_resp = cdp
         .execute_on_tab(
@@ -251,11 +398,11 @@ async fn collect_parents(
 
     let nodes = match ax_resp {
         Ok(ref v) => v["result"]["nodes"].as_array().cloned().unwrap_or_default(),
-        Err(_) => return Ok(json!([])),
+        Err(_) => return Vec::new(),
     };
 
     if nodes.is_empty() {
-        return Ok(json!([]));
+        return Vec::new();
     }
 
     // Build a map from AX nodeId → index in nodes array for O(1) lookups.
@@ -318,16 +465,17 @@ async fn collect_parents(
             ref_cache.get_or_assign(parent_idx as i64, &role, &name, None)
         };
 
-        parents.push(json!({
-            "role": role,
-            "name": name,
-            "selector": selector,
-        }));
+        parents.push(InspectedNode {
+            role,
+            name,
+            selector,
+            state: ElementState::default(),
+        });
 
         current_ax_id = Some(parent_ax_id);
     }
 
-    Ok(json!(parents))
+    parents
 }
 
 #[cfg(test)]
@@ -383,6 +531,57 @@ mod tests {
         assert!(err.contains("invalid"));
     }
 
+    #[test]
+    fn inspect_result_serializes_legacy_shape() {
+        let result = InspectResult {
+            point: Some(Point { x: 10.0, y: 20.0 }),
+            element: Some(InspectedNode {
+                role: "button".to_string(),
+                name: "Submit".to_string(),
+                selector: "@e4".to_string(),
+                state: ElementState {
+                    disabled: true,
+                    ..ElementState::default()
+                },
+            }),
+            parents: vec![InspectedNode {
+                role: "form".to_string(),
+                name: String::new(),
+                selector: "@e1".to_string(),
+                state: ElementState::default(),
+            }],
+        };
+        let data = result
+            .clone()
+            .into_data(json!({ "__ctx_url": "about:blank" }));
+
+        assert_eq!(
+            data,
+            json!({
+                "point": { "x": 10.0, "y": 20.0 },
+                "element": { "role": "button", "name": "Submit", "selector": "@e4", "disabled": true },
+                "parents": [{ "role": "form", "name": "", "selector": "@e1" }],
+                "__ctx_url": "about:blank",
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<InspectResult>(data).unwrap(),
+            result
+        );
+    }
+
+    #[test]
+    fn inspect_result_serializes_miss_as_null_element() {
+        let data = InspectResult {
+            point: Some(Point { x: 1.0, y: 2.0 }),
+            ..InspectResult::default()
+        }
+        .into_data(json!({}));
+
+        assert_eq!(data["element"], Value::Null);
+        assert_eq!(data["parents"], json!([]));
+    }
+
     #[test]
     fn parse_coordinates_extra_commas() {
         // splitn(2, ',') treats "1,2,3" as ["1", "2,3"] — "2,3" fails f64 parse
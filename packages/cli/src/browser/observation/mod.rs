@@ -3,6 +3,7 @@ pub mod attrs;
 pub mod batch_snapshot;
 pub mod r#box;
 pub mod describe;
+pub mod exists;
 pub mod html;
 pub mod inspect_point;
 pub mod logs_console;
@@ -22,3 +23,4 @@ pub mod title;
 pub mod url;
 pub mod value;
 pub mod viewport;
+pub mod visible;
@@ -582,7 +582,7 @@ fn wall_time_to_rfc3339(wall_time: f64) -> String {
 
 /// Format a Unix timestamp (seconds + milliseconds) as RFC 3339 / ISO 8601 UTC.
 /// Output: `YYYY-MM-DDTHH:MM:SS.mmmZ`
-fn unix_secs_to_rfc3339(secs: u64, millis: u64) -> String {
+pub(crate) fn unix_secs_to_rfc3339(secs: u64, millis: u64) -> String {
     // Days since Unix epoch → calendar date (Gregorian proleptic)
     let days = secs / 86400;
     let time_of_day = secs % 86400;
@@ -2,7 +2,7 @@ use base64::Engine as _;
 use base64::engine::general_purpose::STANDARD as BASE64;
 use clap::Args;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde_json::{Value, json};
 
 use crate::action_result::ActionResult;
 use crate::browser::navigation;
@@ -12,6 +12,13 @@ use crate::output::ResponseContext;
 
 /// Save the current page as a PDF.
 #[derive(Args, Debug, Clone, Serialize, Deserialize)]
+#[command(after_help = "\
+Examples:
+  actionbook browser pdf /tmp/page.pdf --session s1 --tab t1
+  actionbook browser pdf /tmp/contract.pdf --format a4 --landscape --session s1 --tab t1
+  actionbook browser pdf /tmp/report.pdf --margin-top 0.5 --margin-bottom 0.5 --print-background --session s1 --tab t1
+
+Margins are in inches. Without options the output matches Chrome's print defaults (Letter, portrait).")]
 pub struct Cmd {
     /// Output file path
     pub path: String,
@@ -23,6 +30,38 @@ pub struct Cmd {
     #[arg(long)]
     #[serde(rename = "tab_id")]
     pub tab: String,
+    /// Paper size (letter, legal, tabloid, ledger, a3, a4, a5)
+    #[arg(long)]
+    #[serde(default)]
+    pub format: Option<String>,
+    /// Landscape orientation
+    #[arg(long)]
+    #[serde(default)]
+    pub landscape: bool,
+    /// Top margin in inches
+    #[arg(long)]
+    #[serde(default)]
+    pub margin_top: Option<f64>,
+    /// Bottom margin in inches
+    #[arg(long)]
+    #[serde(default)]
+    pub margin_bottom: Option<f64>,
+    /// Left margin in inches
+    #[arg(long)]
+    #[serde(default)]
+    pub margin_left: Option<f64>,
+    /// Right margin in inches
+    #[arg(long)]
+    #[serde(default)]
+    pub margin_right: Option<f64>,
+    /// Include CSS background colors and images
+    #[arg(long)]
+    #[serde(default)]
+    pub print_background: bool,
+    /// Rendering scale (0.1 to 2, default 1)
+    #[arg(long)]
+    #[serde(default)]
+    pub scale: Option<f64>,
 }
 
 pub const COMMAND_NAME: &str = "browser pdf";
@@ -60,6 +99,72 @@ pub fn context(cmd: &Cmd, result: &ActionResult) -> Option<ResponseContext> {
     })
 }
 
+/// Paper size name → (width, height) in inches, portrait.
+fn paper_size(format: &str) -> Option<(f64, f64)> {
+    match format.to_ascii_lowercase().as_str() {
+        "letter" => Some((8.5, 11.0)),
+        "legal" => Some((8.5, 14.0)),
+        "tabloid" => Some((11.0, 17.0)),
+        "ledger" => Some((17.0, 11.0)),
+        "a3" => Some((11.69, 16.54)),
+        "a4" => Some((8.27, 11.69)),
+        "a5" => Some((5.83, 8.27)),
+        _ => None,
+    }
+}
+
+/// Build `Page.printToPDF` params. Options left unset are omitted so Chrome's
+/// defaults apply.
+fn print_params(cmd: &Cmd) -> Result<Value, ActionResult> {
+    let mut params = json!({ "transferMode": "ReturnAsBase64" });
+
+    if let Some(ref format) = cmd.format {
+        let (width, height) = paper_size(format).ok_or_else(|| {
+            ActionResult::fatal(
+                "INVALID_ARGUMENT",
+                format!(
+                    "unsupported paper format: '{format}' (supported: letter, legal, tabloid, ledger, a3, a4, a5)"
+                ),
+            )
+        })?;
+        params["paperWidth"] = json!(width);
+        params["paperHeight"] = json!(height);
+    }
+    if cmd.landscape {
+        params["landscape"] = json!(true);
+    }
+    for (key, value) in [
+        ("marginTop", cmd.margin_top),
+        ("marginBottom", cmd.margin_bottom),
+        ("marginLeft", cmd.margin_left),
+        ("marginRight", cmd.margin_right),
+    ] {
+        if let Some(inches) = value {
+            if !inches.is_finite() || inches < 0.0 {
+                return Err(ActionResult::fatal(
+                    "INVALID_ARGUMENT",
+                    format!("margins must be non-negative inches, got {inches}"),
+                ));
+            }
+            params[key] = json!(inches);
+        }
+    }
+    if cmd.print_background {
+        params["printBackground"] = json!(true);
+    }
+    if let Some(scale) = cmd.scale {
+        if !(0.1..=2.0).contains(&scale) {
+            return Err(ActionResult::fatal(
+                "INVALID_ARGUMENT",
+                format!("scale must be between 0.1 and 2, got {scale}"),
+            ));
+        }
+        params["scale"] = json!(scale);
+    }
+
+    Ok(params)
+}
+
 pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
     let (cdp, target_id) = match get_cdp_and_target(registry, &cmd.session, &cmd.tab).await {
         Ok(v) => v,
@@ -69,14 +174,13 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
     let url = navigation::get_tab_url(&cdp, &target_id).await;
     let title = navigation::get_tab_title(&cdp, &target_id).await;
 
+    let params = match print_params(cmd) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
     let resp = cdp
-        .execute_on_tab(
-            &target_id,
-            "Page.printToPDF",
-            json!({
-                "transferMode": "ReturnAsBase64",
-            }),
-        )
+        .execute_on_tab(&target_id, "Page.printToPDF", params)
         .await
         .map_err(|e| cdp_error_to_result(e, "CDP_ERROR"));
 
@@ -114,3 +218,75 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         "__ctx_title": title,
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cmd() -> Cmd {
+        Cmd {
+            path: "/tmp/out.pdf".to_string(),
+            session: "s1".to_string(),
+            tab: "t1".to_string(),
+            format: None,
+            landscape: false,
+            margin_top: None,
+            margin_bottom: None,
+            margin_left: None,
+            margin_right: None,
+            print_background: false,
+            scale: None,
+        }
+    }
+
+    #[test]
+    fn print_params_default_matches_plain_print() {
+        assert_eq!(
+            print_params(&cmd()).unwrap(),
+            json!({ "transferMode": "ReturnAsBase64" })
+        );
+    }
+
+    #[test]
+    fn print_params_maps_options_to_cdp_fields() {
+        let params = print_params(&Cmd {
+            format: Some("A4".to_string()),
+            landscape: true,
+            margin_top: Some(0.5),
+            margin_left: Some(0.0),
+            print_background: true,
+            scale: Some(0.8),
+            ..cmd()
+        })
+        .unwrap();
+
+        assert_eq!(params["paperWidth"], 8.27);
+        assert_eq!(params["paperHeight"], 11.69);
+        assert_eq!(params["landscape"], true);
+        assert_eq!(params["marginTop"], 0.5);
+        assert_eq!(params["marginLeft"], 0.0);
+        assert!(params.get("marginBottom").is_none());
+        assert_eq!(params["printBackground"], true);
+        assert_eq!(params["scale"], 0.8);
+    }
+
+    #[test]
+    fn print_params_rejects_invalid_values() {
+        for bad in [
+            Cmd {
+                format: Some("b5".to_string()),
+                ..cmd()
+            },
+            Cmd {
+                margin_right: Some(-1.0),
+                ..cmd()
+            },
+            Cmd {
+                scale: Some(3.0),
+                ..cmd()
+            },
+        ] {
+            assert!(print_params(&bad).is_err());
+        }
+    }
+}
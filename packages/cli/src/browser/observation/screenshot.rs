@@ -36,6 +36,10 @@ pub struct Cmd {
     /// CSS selector to limit capture region
     #[arg(long)]
     pub selector: Option<String>,
+    /// Page region to capture: x,y,width,height in CSS pixels
+    #[arg(long, conflicts_with_all = ["full", "selector"])]
+    #[serde(default)]
+    pub clip: Option<String>,
 }
 
 pub const COMMAND_NAME: &str = "browser screenshot";
@@ -128,6 +132,31 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
             "--full and --selector are mutually exclusive",
         );
     }
+    if cmd.clip.is_some() && (cmd.full || cmd.selector.is_some()) {
+        return ActionResult::fatal(
+            "INVALID_ARGUMENT",
+            "--clip cannot be combined with --full or --selector",
+        );
+    }
+    let clip_rect = match cmd.clip.as_deref().map(parse_clip).transpose() {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    // The clip is in page coordinates; annotations work in viewport coordinates.
+    let clip_viewport_rect = match clip_rect {
+        Some(ref clip) => {
+            let (sx, sy) = get_scroll_offsets(&ctx.cdp, &ctx.target_id)
+                .await
+                .unwrap_or((0.0, 0.0));
+            Some(Rect {
+                x: clip.x - sx,
+                y: clip.y - sy,
+                width: clip.width,
+                height: clip.height,
+            })
+        }
+        None => None,
+    };
 
     // ── Annotate: collect rects, inject overlay ───────────────────
     let mut overlay_injected = false;
@@ -147,11 +176,13 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
             reg.put_ref_cache(&cmd.session, &cmd.tab, ref_cache);
         }
 
-        // Filter by selector region if applicable
+        // Filter by selector or clip region if applicable
         if let Some(ref sel) = cmd.selector
             && let Ok((_, target_rect)) = get_selector_rect(&mut ctx, sel).await
         {
             annotation_items = filter_annotations(annotation_items, Some(&target_rect));
+        } else if let Some(ref target_rect) = clip_viewport_rect {
+            annotation_items = filter_annotations(annotation_items, Some(target_rect));
         }
 
         if !annotation_items.is_empty()
@@ -197,6 +228,15 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
                 params["captureBeyondViewport"] = json!(true);
             }
         }
+    } else if let Some(ref clip) = clip_rect {
+        params["clip"] = json!({
+            "x": clip.x,
+            "y": clip.y,
+            "width": clip.width,
+            "height": clip.height,
+            "scale": 1,
+        });
+        params["captureBeyondViewport"] = json!(true);
     } else if let Some(ref sel) = cmd.selector {
         // Clip to selector region
         match get_selector_rect(&mut ctx, sel).await {
@@ -284,7 +324,7 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         let selector_rect = if let Some(ref sel) = cmd.selector {
             get_selector_rect(&mut ctx, sel).await.ok().map(|(_, r)| r)
         } else {
-            None
+            clip_viewport_rect
         };
 
         let annotations = project_annotations(&annotation_items, selector_rect.as_ref(), scroll);
@@ -571,6 +611,13 @@ async fn get_selector_rect(
         .ok_or_else(|| {
             ActionResult::fatal("CDP_ERROR", format!("failed to get rect for: {selector}"))
         })?;
+    if rect.width <= 0.0 || rect.height <= 0.0 {
+        return Err(ActionResult::fatal_with_hint(
+            "INVALID_ARGUMENT",
+            format!("element '{selector}' has zero area and cannot be captured"),
+            "the element may be hidden (display:none) or collapsed; pick a visible element",
+        ));
+    }
 
     let clip = json!({
         "x": rect.x,
@@ -583,6 +630,34 @@ async fn get_selector_rect(
     Ok((clip, rect))
 }
 
+/// Parse `--clip x,y,width,height` into a page-coordinate rect.
+fn parse_clip(input: &str) -> Result<Rect, ActionResult> {
+    let invalid = || {
+        ActionResult::fatal_with_hint(
+            "INVALID_ARGUMENT",
+            format!("invalid --clip: '{input}'"),
+            "expected x,y,width,height in CSS pixels, e.g. --clip 0,0,800,600",
+        )
+    };
+    let parts = input
+        .split(',')
+        .map(|p| p.trim().parse::<f64>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| invalid())?;
+    let [x, y, width, height] = parts[..] else {
+        return Err(invalid());
+    };
+    if !parts.iter().all(|v| v.is_finite()) || x < 0.0 || y < 0.0 || width <= 0.0 || height <= 0.0 {
+        return Err(invalid());
+    }
+    Ok(Rect {
+        x,
+        y,
+        width,
+        height,
+    })
+}
+
 fn project_annotations(
     items: &[AnnotationItem],
     target_rect: Option<&Rect>,
@@ -629,6 +704,29 @@ fn project_annotations(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_clip_accepts_four_numbers() {
+        let rect = parse_clip("10, 20,300.5,40").expect("valid clip");
+        assert_eq!(
+            (rect.x, rect.y, rect.width, rect.height),
+            (10.0, 20.0, 300.5, 40.0)
+        );
+    }
+
+    #[test]
+    fn test_parse_clip_rejects_malformed_or_empty_regions() {
+        for bad in [
+            "",
+            "1,2,3",
+            "1,2,3,4,5",
+            "a,b,c,d",
+            "0,0,0,10",
+            "-1,0,10,10",
+        ] {
+            assert!(parse_clip(bad).is_err(), "expected '{bad}' to be rejected");
+        }
+    }
+
     #[test]
     fn test_infer_format_png() {
         assert_eq!(infer_format("/tmp/test.png"), "png");
@@ -23,6 +23,8 @@ Examples:
   actionbook browser snapshot -i --session s1 --tab t1
   actionbook browser snapshot -i -c --session s1 --tab t1
   actionbook browser snapshot --depth 3 --session s1 --tab t1
+  actionbook browser snapshot --role heading,link --session s1 --tab t1
+  actionbook browser snapshot --diff --session s1 --tab t1
   actionbook browser snapshot --selector \"#main\" --session s1 --tab t1
 
 The default snapshot contains all information including interactive elements,
@@ -34,6 +36,12 @@ in other commands: click @e5, fill @e7 \"text\", hover @e3.
 Refs are stable across snapshots — if the DOM node stays the same, the ref
 stays the same. This lets agents chain commands without re-snapshotting.
 
+--diff also reports which refs were added, removed or changed since the
+previous snapshot of this tab taken with the same filter flags (e.g. snapshot
+-i, click @e5, snapshot -i --diff). If the tab navigated in between, refs are
+reissued, so every old node is reported removed and every new node added.
+Only the last few flag combinations per tab are kept as baselines.
+
 Sample output:
   - generic
     - link \"Home\" [ref=e8] url=https://example.com/
@@ -72,6 +80,14 @@ pub struct Cmd {
     #[arg(long, short = 's')]
     #[serde(default)]
     pub selector: Option<String>,
+    /// Include only nodes with these roles (comma-separated or repeated, e.g. heading,link)
+    #[arg(long, value_delimiter = ',')]
+    #[serde(default)]
+    pub role: Vec<String>,
+    /// Also report nodes added, removed or changed since this tab's previous snapshot with the same filters
+    #[arg(long, default_value_t = false)]
+    #[serde(default)]
+    pub diff: bool,
 }
 
 pub const COMMAND_NAME: &str = "browser snapshot";
@@ -153,6 +169,7 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         compact: cmd.compact,
         depth: cmd.depth.map(|d| d as usize),
         selector: cmd.selector.clone(),
+        roles: cmd.role.clone(),
     };
 
     // Parse and transform the AX tree
@@ -209,15 +226,21 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         }
     };
 
-    // Store RefCache back (single lock)
-    {
-        let mut reg = registry.lock().await;
-        reg.put_ref_cache(&cmd.session, &cmd.tab, ref_cache);
-    }
-
     // Build output per §10.1
     let output = snapshot_transform::build_output(nodes);
 
+    // Store RefCache back and record this snapshot as the next diff baseline (single lock)
+    let previous = {
+        let mut reg = registry.lock().await;
+        reg.put_ref_cache(&cmd.session, &cmd.tab, ref_cache);
+        reg.replace_last_snapshot(
+            &cmd.session,
+            &cmd.tab,
+            &baseline_key(cmd),
+            output.nodes.clone(),
+        )
+    };
+
     // Write snapshot content to a file in the session data directory.
     let session_data_dir = crate::config::session_data_dir(&cmd.session);
     let ts = std::time::SystemTime::now()
@@ -251,9 +274,37 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
     if let Some(ref warning) = cursor_warning {
         data["__warnings"] = json!([warning]);
     }
+    if cmd.diff {
+        data["has_baseline"] = json!(previous.is_some());
+        data["diff"] = json!(match previous {
+            Some(ref p) if p.navigated => {
+                snapshot_transform::diff_across_navigation(&p.nodes, &output.nodes)
+            }
+            Some(ref p) => snapshot_transform::diff_nodes(&p.nodes, &output.nodes),
+            None => snapshot_transform::diff_nodes(&[], &output.nodes),
+        });
+    }
     ActionResult::ok(data)
 }
 
+/// Identifies the filter flags a snapshot was taken with. `--diff` only
+/// compares snapshots with the same key, since differently filtered snapshots
+/// would report every filtered-out node as removed or added.
+fn baseline_key(cmd: &Cmd) -> String {
+    let mut roles: Vec<String> = cmd.role.iter().map(|r| r.to_ascii_lowercase()).collect();
+    roles.sort();
+    roles.dedup();
+    json!([
+        cmd.interactive,
+        cmd.compact,
+        cmd.cursor,
+        cmd.depth,
+        cmd.selector,
+        roles,
+    ])
+    .to_string()
+}
+
 // ── iframe expansion helpers ──────────────────────────────────────
 
 /// Resolve the child frame ID for an iframe element given its backendNodeId.
@@ -26,6 +26,8 @@ pub struct AXNode {
     pub url: String,
     /// Whether this node is considered interactive
     pub interactive: bool,
+    /// Disabled/required/focused flags (interactive roles only)
+    pub state: ElementState,
     /// Tree depth (0 = root)
     pub depth: usize,
     /// Children
@@ -46,6 +48,8 @@ pub struct SnapshotOptions {
     pub depth: Option<usize>,
     /// CSS selector to limit subtree (None = whole page)
     pub selector: Option<String>,
+    /// Keep only nodes with one of these roles (empty = all roles)
+    pub roles: Vec<String>,
 }
 
 /// Snapshot output ready to serialise as §10.1 data.
@@ -64,6 +68,62 @@ pub struct NodeEntry {
     pub role: String,
     pub name: String,
     pub value: String,
+    #[serde(flatten)]
+    pub state: ElementState,
+}
+
+/// Element state flags read from CDP AX properties.
+/// Only `true` flags are serialised so the output stays lean.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ElementState {
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub required: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub focused: bool,
+}
+
+impl ElementState {
+    /// Read state flags from an AX node's `properties` array.
+    /// Non-interactive roles always get the empty state.
+    pub fn from_ax_node(role: &str, node: &Value) -> Self {
+        if !is_interactive_role(role) {
+            return Self::default();
+        }
+        let flag = |name: &str| {
+            node["properties"].as_array().is_some_and(|props| {
+                props
+                    .iter()
+                    .any(|p| p["name"].as_str() == Some(name) && p["value"]["value"] == true)
+            })
+        };
+        Self {
+            disabled: flag("disabled"),
+            required: flag("required"),
+            focused: flag("focused"),
+        }
+    }
+
+    /// Names of the flags that are set, in a fixed order.
+    pub fn set_flags(&self) -> Vec<&'static str> {
+        [
+            (self.disabled, "disabled"),
+            (self.required, "required"),
+            (self.focused, "focused"),
+        ]
+        .into_iter()
+        .filter_map(|(set, name)| set.then_some(name))
+        .collect()
+    }
+
+    /// Bracketed labels for the `content` tree, e.g. ` [disabled] [focused]`.
+    fn labels(&self) -> String {
+        self.set_flags()
+            .into_iter()
+            .map(|name| format!(" [{name}]"))
+            .collect()
+    }
 }
 
 /// Roles considered interactive per §10.1.
@@ -204,6 +264,7 @@ pub fn render_yaml(nodes: &[AXNode]) -> String {
             if !node.ref_id.is_empty() {
                 s.push_str(&format!(" [ref={}]", node.ref_id));
             }
+            s.push_str(&node.state.labels());
             if has_cursor_pointer {
                 s.push_str(" [cursor=pointer]");
             }
@@ -215,6 +276,7 @@ pub fn render_yaml(nodes: &[AXNode]) -> String {
                 s.push_str(&format!(" \"{}\"", escaped_name));
             }
             s.push_str(&format!(" [ref={}]", node.ref_id));
+            s.push_str(&node.state.labels());
             if has_cursor_pointer {
                 s.push_str(" [cursor=pointer]");
             }
@@ -418,6 +480,16 @@ pub fn parse_ax_tree(
             return;
         }
 
+        // Role filter: same promotion as --interactive. Iframe nodes are kept
+        // so their content can still be expanded (and filtered) afterwards.
+        if !options.roles.is_empty()
+            && role != "Iframe"
+            && !options.roles.iter().any(|r| r.eq_ignore_ascii_case(&role))
+        {
+            render_children(depth, result, ref_cache);
+            return;
+        }
+
         // Extract value (handles string, number, bool)
         let value = extract_ax_string(&node["value"]);
 
@@ -453,6 +525,7 @@ pub fn parse_ax_tree(
             String::new()
         };
 
+        let state = ElementState::from_ax_node(&role, node);
         result.push(AXNode {
             ref_id,
             role,
@@ -460,6 +533,7 @@ pub fn parse_ax_tree(
             value,
             url,
             interactive: is_interactive || is_cursor,
+            state,
             depth,
             children: vec![],
             cursor_info,
@@ -525,6 +599,7 @@ pub fn build_output(nodes: Vec<AXNode>) -> SnapshotOutput {
             role: n.role.clone(),
             name: n.name.clone(),
             value: n.value.clone(),
+            state: n.state,
         })
         .collect();
     SnapshotOutput {
@@ -535,6 +610,64 @@ pub fn build_output(nodes: Vec<AXNode>) -> SnapshotOutput {
     }
 }
 
+/// Ref-keyed difference between two snapshots' `data.nodes`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotDiff {
+    pub added: Vec<NodeEntry>,
+    pub removed: Vec<NodeEntry>,
+    pub changed: Vec<NodeChange>,
+}
+
+/// A ref present in both snapshots whose role, name, value or state differs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeChange {
+    pub before: NodeEntry,
+    pub after: NodeEntry,
+}
+
+impl SnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Diff two node lists by ref. Added and changed entries follow `after`'s
+/// order, removed entries follow `before`'s.
+pub fn diff_nodes(before: &[NodeEntry], after: &[NodeEntry]) -> SnapshotDiff {
+    let old: std::collections::HashMap<&str, &NodeEntry> =
+        before.iter().map(|n| (n.r#ref.as_str(), n)).collect();
+    let new: std::collections::HashSet<&str> = after.iter().map(|n| n.r#ref.as_str()).collect();
+
+    let mut diff = SnapshotDiff::default();
+    for node in after {
+        match old.get(node.r#ref.as_str()) {
+            None => diff.added.push(node.clone()),
+            Some(prev) if *prev != node => diff.changed.push(NodeChange {
+                before: (*prev).clone(),
+                after: node.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+    diff.removed = before
+        .iter()
+        .filter(|n| !new.contains(n.r#ref.as_str()))
+        .cloned()
+        .collect();
+    diff
+}
+
+/// Diff against a snapshot taken before a navigation. Refs are reissued on
+/// navigation, so matching by ref would pair unrelated nodes: report every
+/// old node as removed and every new one as added instead.
+pub fn diff_across_navigation(before: &[NodeEntry], after: &[NodeEntry]) -> SnapshotDiff {
+    SnapshotDiff {
+        added: after.to_vec(),
+        removed: before.to_vec(),
+        changed: Vec::new(),
+    }
+}
+
 // ── P0: Role & noise classification ──────────────────────────────────
 
 /// Noise roles to skip entirely during tree traversal.
@@ -974,6 +1107,7 @@ mod tests {
             value: String::new(),
             url: String::new(),
             interactive,
+            state: ElementState::default(),
             depth,
             children: vec![],
             cursor_info: None,
@@ -995,6 +1129,7 @@ mod tests {
             value: value.to_string(),
             url: String::new(),
             interactive,
+            state: ElementState::default(),
             depth,
             children: vec![],
             cursor_info: None,
@@ -1359,6 +1494,80 @@ mod tests {
         assert!(nodes.iter().all(|n| n.interactive));
     }
 
+    #[test]
+    fn test_parse_ax_tree_role_filter() {
+        let response = serde_json::json!({
+            "result": {
+                "nodes": [
+                    {
+                        "nodeId": "1", "role": {"value": "navigation"},
+                        "name": {"value": "Nav"}, "childIds": ["2", "3"]
+                    },
+                    { "nodeId": "2", "role": {"value": "heading"}, "name": {"value": "Title"} },
+                    { "nodeId": "3", "role": {"value": "link"}, "name": {"value": "Home"} },
+                    { "nodeId": "4", "role": {"value": "button"}, "name": {"value": "Go"} },
+                ]
+            }
+        });
+        let opts = SnapshotOptions {
+            roles: vec!["Heading".to_string(), "button".to_string()],
+            ..Default::default()
+        };
+        let nodes = parse_ax_tree(&response, &opts, &mut RefCache::new(), None, None, None);
+        let roles: Vec<&str> = nodes.iter().map(|n| n.role.as_str()).collect();
+        assert_eq!(roles, ["heading", "button"]);
+        // Children of a dropped node are promoted to its depth.
+        assert_eq!(nodes[0].depth, 0);
+    }
+
+    fn entry(r: &str, role: &str, name: &str, value: &str) -> NodeEntry {
+        NodeEntry {
+            r#ref: r.to_string(),
+            role: role.to_string(),
+            name: name.to_string(),
+            value: value.to_string(),
+            state: ElementState::default(),
+        }
+    }
+
+    #[test]
+    fn test_diff_nodes_reports_added_removed_changed() {
+        let before = vec![
+            entry("e1", "button", "Open", ""),
+            entry("e2", "textbox", "Email", ""),
+            entry("e3", "link", "Help", ""),
+        ];
+        let mut focused = entry("e2", "textbox", "Email", "a@b.c");
+        focused.state.focused = true;
+        let after = vec![
+            entry("e1", "button", "Open", ""),
+            focused.clone(),
+            entry("e4", "dialog", "Sign in", ""),
+        ];
+
+        let diff = diff_nodes(&before, &after);
+        assert_eq!(diff.added, [entry("e4", "dialog", "Sign in", "")]);
+        assert_eq!(diff.removed, [entry("e3", "link", "Help", "")]);
+        assert_eq!(
+            diff.changed,
+            [NodeChange {
+                before: entry("e2", "textbox", "Email", ""),
+                after: focused,
+            }]
+        );
+        assert!(diff_nodes(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn test_diff_across_navigation_does_not_match_reissued_refs() {
+        let before = vec![entry("e1", "button", "Open", "")];
+        let after = vec![entry("e1", "link", "Home", "")];
+        let diff = diff_across_navigation(&before, &after);
+        assert_eq!(diff.removed, before);
+        assert_eq!(diff.added, after);
+        assert!(diff.changed.is_empty());
+    }
+
     #[test]
     fn test_parse_ax_tree_compact_filter() {
         let response = serde_json::json!({
@@ -1876,6 +2085,90 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_output_surfaces_state_flags_for_interactive_roles() {
+        let response = serde_json::json!({
+            "result": {
+                "nodes": [
+                    {
+                        "nodeId": "1",
+                        "role": {"value": "RootWebArea"},
+                        "name": {"value": ""},
+                        "childIds": ["2", "3"]
+                    },
+                    {
+                        "nodeId": "2",
+                        "backendDOMNodeId": 60,
+                        "role": {"value": "button"},
+                        "name": {"value": "Submit"},
+                        "childIds": [],
+                        "properties": [
+                            { "name": "disabled", "value": { "type": "boolean", "value": true } },
+                            { "name": "focusable", "value": { "type": "boolean", "value": true } }
+                        ]
+                    },
+                    {
+                        "nodeId": "3",
+                        "backendDOMNodeId": 61,
+                        "role": {"value": "heading"},
+                        "name": {"value": "Title"},
+                        "childIds": [],
+                        "properties": [
+                            { "name": "focused", "value": { "type": "boolean", "value": true } }
+                        ]
+                    }
+                ]
+            }
+        });
+        let nodes = parse_ax_tree(
+            &response,
+            &SnapshotOptions::default(),
+            &mut RefCache::new(),
+            None,
+            None,
+            None,
+        );
+        let output = build_output(nodes);
+
+        assert!(
+            output
+                .content
+                .contains("- button \"Submit\" [ref=e1] [disabled]"),
+            "disabled button should be labelled: {}",
+            output.content
+        );
+        let button = serde_json::to_value(&output.nodes[0]).unwrap();
+        assert_eq!(button["disabled"], true);
+        assert!(button.get("required").is_none());
+        assert!(button.get("focused").is_none());
+
+        // Non-interactive roles never carry state flags.
+        let heading = serde_json::to_value(&output.nodes[1]).unwrap();
+        assert!(heading.get("focused").is_none());
+        assert!(!output.content.contains("[focused]"));
+    }
+
+    #[test]
+    fn test_element_state_reads_required_and_focused() {
+        let node = serde_json::json!({
+            "properties": [
+                { "name": "required", "value": { "type": "boolean", "value": true } },
+                { "name": "focused", "value": { "type": "boolean", "value": true } },
+                { "name": "disabled", "value": { "type": "boolean", "value": false } }
+            ]
+        });
+        let state = ElementState::from_ax_node("textbox", &node);
+        assert_eq!(
+            state,
+            ElementState {
+                disabled: false,
+                required: true,
+                focused: true,
+            }
+        );
+        assert_eq!(state.labels(), " [required] [focused]");
+    }
+
     // ══════════════════════════════════════════════════════════════════
     // P0: remove_empty_leaves
     // ══════════════════════════════════════════════════════════════════
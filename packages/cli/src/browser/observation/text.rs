@@ -18,7 +18,9 @@ Examples:
 
 Accepts a CSS selector, XPath, or snapshot ref (@eN from snapshot output).
 Without a selector, returns the full page innerText.
-With a selector, returns the innerText of the matched element.")]
+With a selector, returns the innerText of the matched element.
+Whitespace is normalized and script/style/noscript text is dropped;
+pass --raw to get the unprocessed innerText.")]
 pub struct Cmd {
     /// Selector (CSS, XPath, or @ref). Omit to read the full page text.
     pub selector: Option<String>,
@@ -30,6 +32,10 @@ pub struct Cmd {
     #[arg(long)]
     #[serde(rename = "tab_id")]
     pub tab: String,
+    /// Return the unprocessed innerText (skip whitespace normalization)
+    #[arg(long)]
+    #[serde(default)]
+    pub raw: bool,
 }
 
 pub const COMMAND_NAME: &str = "browser text";
@@ -73,7 +79,8 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         Err(e) => return e,
     };
 
-    let value = match get_text(&mut ctx, cmd.selector.as_deref()).await {
+    let value = match get_text(&mut ctx, cmd.selector.as_deref(), cmd.raw).await {
+        Ok(Value::String(text)) if !cmd.raw => Value::String(normalize_text(&text)),
         Ok(v) => v,
         Err(e) => return e,
     };
@@ -88,7 +95,46 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
     }))
 }
 
-async fn get_text(ctx: &mut TabContext, selector: Option<&str>) -> Result<Value, ActionResult> {
+/// Page-side text extraction. `innerText` already skips script/style when the
+/// element is rendered, but falls back to `textContent` semantics for hidden
+/// subtrees and detached frames — so in non-raw mode we walk those ourselves
+/// and drop script/style/noscript/template content.
+const TEXT_FN: &str = r#"function(raw) {
+    const SKIP = new Set(['SCRIPT', 'STYLE', 'NOSCRIPT', 'TEMPLATE']);
+    const BLOCK = /^(ADDRESS|ARTICLE|ASIDE|BLOCKQUOTE|BR|DD|DIV|DL|DT|FIELDSET|FIGCAPTION|FIGURE|FOOTER|FORM|H[1-6]|HEADER|HR|LI|MAIN|NAV|OL|P|PRE|SECTION|TABLE|TR|UL)$/;
+    const walk = (node, out) => {
+        for (const child of node.childNodes) {
+            if (child.nodeType === 3) {
+                out.push(child.nodeValue);
+            } else if (child.nodeType === 1 && !SKIP.has(child.tagName)) {
+                const block = BLOCK.test(child.tagName);
+                if (block) out.push('\n');
+                walk(child, out);
+                if (block) out.push('\n');
+            }
+        }
+        return out;
+    };
+    const read = (el) => {
+        if (!el) return '';
+        if (raw || el.getClientRects().length > 0) return el.innerText;
+        return walk(el, []).join('');
+    };
+    if (this.tagName === 'IFRAME' || this.tagName === 'FRAME') {
+        try {
+            return this.contentDocument ? read(this.contentDocument.body) : '';
+        } catch(e) {
+            return '';
+        }
+    }
+    return read(this);
+}"#;
+
+async fn get_text(
+    ctx: &mut TabContext,
+    selector: Option<&str>,
+    raw: bool,
+) -> Result<Value, ActionResult> {
     match selector {
         Some(selector) => {
             let (_, object_id) = ctx.resolve_object(selector).await?;
@@ -97,16 +143,8 @@ async fn get_text(ctx: &mut TabContext, selector: Option<&str>) -> Result<Value,
                     "Runtime.callFunctionOn",
                     json!({
                         "objectId": object_id,
-                        "functionDeclaration": r#"function() {
-                    if (this.tagName === 'IFRAME' || this.tagName === 'FRAME') {
-                        try {
-                            return this.contentDocument ? this.contentDocument.body.innerText : '';
-                        } catch(e) {
-                            return '';
-                        }
-                    }
-                    return this.innerText;
-                }"#,
+                        "functionDeclaration": TEXT_FN,
+                        "arguments": [{ "value": raw }],
                         "returnByValue": true,
                     }),
                 )
@@ -144,3 +182,40 @@ async fn get_text(ctx: &mut TabContext, selector: Option<&str>) -> Result<Value,
         }
     }
 }
+
+/// Collapse whitespace runs to single spaces, trim each line, and drop blank
+/// lines so paragraph breaks survive as a single `\n`.
+fn normalize_text(text: &str) -> String {
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_text_collapses_runs_and_trims_lines() {
+        assert_eq!(
+            normalize_text("  Story\t\tTitle  \n   Primary   article copy. "),
+            "Story Title\nPrimary article copy."
+        );
+    }
+
+    #[test]
+    fn normalize_text_keeps_paragraph_breaks_as_single_newlines() {
+        assert_eq!(
+            normalize_text("First\n\n\n  \nSecond\r\n\r\nThird"),
+            "First\nSecond\nThird"
+        );
+    }
+
+    #[test]
+    fn normalize_text_treats_nbsp_as_whitespace() {
+        assert_eq!(normalize_text("a\u{a0}\u{a0}b"), "a b");
+        assert_eq!(normalize_text(" \n\t"), "");
+    }
+}
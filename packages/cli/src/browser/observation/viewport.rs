@@ -3,17 +3,25 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 use crate::action_result::ActionResult;
-use crate::daemon::cdp_session::{cdp_error_to_result, get_cdp_and_target};
+use crate::daemon::cdp_session::{CdpSession, cdp_error_to_result, get_cdp_and_target};
 use crate::daemon::registry::SharedRegistry;
+use crate::error::CliError;
 use crate::output::ResponseContext;
+use crate::types::Mode;
+
+/// Largest width/height accepted by `--set`.
+const MAX_VIEWPORT_DIMENSION: u32 = 10_000;
 
 /// Get current viewport dimensions
 #[derive(Args, Debug, Clone, Serialize, Deserialize)]
 #[command(after_help = "\
 Examples:
   actionbook browser viewport --session s1 --tab t1
+  actionbook browser viewport --set 1280x800 --session s1 --tab t1
 
-Returns width and height in pixels (e.g. 1440x900).")]
+Returns width and height in pixels (e.g. 1440x900).
+--set resizes the window to that viewport and saves it for the session's
+profile, so later local sessions with the same --profile open at that size.")]
 pub struct Cmd {
     /// Session ID
     #[arg(long)]
@@ -23,6 +31,10 @@ pub struct Cmd {
     #[arg(long)]
     #[serde(rename = "tab_id")]
     pub tab: String,
+    /// Resize to WIDTHxHEIGHT and save it for this session's profile
+    #[arg(long, value_name = "WxH")]
+    #[serde(default)]
+    pub set: Option<String>,
 }
 
 pub const COMMAND_NAME: &str = "browser viewport";
@@ -61,6 +73,71 @@ pub fn format_viewport(width: u64, height: u64) -> String {
     format!("{width}x{height}")
 }
 
+/// Parse a `WIDTHxHEIGHT` size such as `1280x800`.
+pub fn parse_viewport(value: &str) -> Result<(u32, u32), String> {
+    let (w, h) = value
+        .trim()
+        .split_once(['x', 'X'])
+        .ok_or_else(|| format!("expected WIDTHxHEIGHT (e.g. 1280x800), got '{value}'"))?;
+    let dim = |s: &str| -> Result<u32, String> {
+        match s.trim().parse::<u32>() {
+            Ok(n) if (1..=MAX_VIEWPORT_DIMENSION).contains(&n) => Ok(n),
+            _ => Err(format!(
+                "viewport dimensions must be between 1 and {MAX_VIEWPORT_DIMENSION}, got '{value}'"
+            )),
+        }
+    };
+    Ok((dim(w)?, dim(h)?))
+}
+
+/// Resize the window holding `target_id` so its viewport (innerWidth ×
+/// innerHeight) is `width`×`height`. The window frame is measured rather than
+/// assumed, so this works for headed and headless Chrome alike.
+pub(crate) async fn resize_viewport(
+    cdp: &CdpSession,
+    target_id: &str,
+    width: u32,
+    height: u32,
+) -> Result<(), CliError> {
+    let window = cdp
+        .execute_browser(
+            "Browser.getWindowForTarget",
+            json!({ "targetId": target_id }),
+        )
+        .await?;
+    let window_id = window["result"]["windowId"].clone();
+
+    let frame = cdp
+        .execute_on_tab(
+            target_id,
+            "Runtime.evaluate",
+            json!({
+                "expression": "({dw: window.outerWidth - window.innerWidth, dh: window.outerHeight - window.innerHeight})",
+                "returnByValue": true
+            }),
+        )
+        .await?;
+    let frame = &frame["result"]["result"]["value"];
+    let dw = frame.get("dw").and_then(|v| v.as_i64()).unwrap_or(0).max(0);
+    let dh = frame.get("dh").and_then(|v| v.as_i64()).unwrap_or(0).max(0);
+
+    // A maximized/fullscreen window ignores width/height, so restore it first.
+    cdp.execute_browser(
+        "Browser.setWindowBounds",
+        json!({ "windowId": window_id, "bounds": { "windowState": "normal" } }),
+    )
+    .await?;
+    cdp.execute_browser(
+        "Browser.setWindowBounds",
+        json!({
+            "windowId": window_id,
+            "bounds": { "width": i64::from(width) + dw, "height": i64::from(height) + dh },
+        }),
+    )
+    .await?;
+    Ok(())
+}
+
 pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
     let (cdp, target_id) = match get_cdp_and_target(registry, &cmd.session, &cmd.tab).await {
         Ok(v) => v,
@@ -69,6 +146,32 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
 
     let url = crate::browser::navigation::get_tab_url(&cdp, &target_id).await;
 
+    let mut saved_profile = None;
+    if let Some(ref size) = cmd.set {
+        let (width, height) = match parse_viewport(size) {
+            Ok(v) => v,
+            Err(e) => return ActionResult::fatal("INVALID_ARGUMENT", e),
+        };
+        let (mode, profile) = match registry.lock().await.get(&cmd.session) {
+            Some(entry) => (entry.mode, entry.profile.clone()),
+            None => return ActionResult::fatal("SESSION_NOT_FOUND", "session not found"),
+        };
+        if mode != Mode::Local {
+            return ActionResult::fatal_with_hint(
+                "UNSUPPORTED_OPERATION",
+                format!("viewport --set is not supported in {mode} mode"),
+                "use `browser emulate --width --height` to override the viewport for this session",
+            );
+        }
+        if let Err(e) = resize_viewport(&cdp, &target_id, width, height).await {
+            return cdp_error_to_result(e, "CDP_ERROR");
+        }
+        if let Err(e) = crate::config::save_profile_viewport(&profile, (width, height)) {
+            return ActionResult::fatal(e.error_code(), format!("failed to save viewport: {e}"));
+        }
+        saved_profile = Some(profile);
+    }
+
     let resp = match cdp
         .execute_on_tab(
             &target_id,
@@ -88,11 +191,15 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
     let width = obj.get("width").and_then(|v| v.as_u64()).unwrap_or(0);
     let height = obj.get("height").and_then(|v| v.as_u64()).unwrap_or(0);
 
-    ActionResult::ok(json!({
+    let mut data = json!({
         "width": width,
         "height": height,
         "__ctx_url": url,
-    }))
+    });
+    if let Some(profile) = saved_profile {
+        data["profile"] = json!(profile);
+    }
+    ActionResult::ok(data)
 }
 
 #[cfg(test)]
@@ -119,6 +226,16 @@ mod tests {
         assert_eq!(format_viewport(800, 800), "800x800");
     }
 
+    #[test]
+    fn test_parse_viewport() {
+        assert_eq!(parse_viewport("1280x800"), Ok((1280, 800)));
+        assert_eq!(parse_viewport(" 375X667 "), Ok((375, 667)));
+        assert!(parse_viewport("1280").is_err());
+        assert!(parse_viewport("0x800").is_err());
+        assert!(parse_viewport("1280x99999").is_err());
+        assert!(parse_viewport("wide x tall").is_err());
+    }
+
     #[test]
     fn test_format_viewport_zero_height() {
         assert_eq!(format_viewport(1280, 0), "1280x0");
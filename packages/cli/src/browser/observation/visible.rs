@@ -0,0 +1,124 @@
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::action_result::ActionResult;
+use crate::browser::{element::TabContext, navigation};
+use crate::daemon::cdp_session::cdp_error_to_result;
+use crate::daemon::registry::SharedRegistry;
+use crate::output::ResponseContext;
+
+use super::exists::find_node;
+
+/// Visible = rendered with a non-empty box and not hidden by CSS
+/// (display, visibility, or an ancestor's display/content-visibility).
+const IS_VISIBLE_FN: &str = r#"function() {
+    if (typeof this.checkVisibility === 'function' && !this.checkVisibility({ visibilityProperty: true })) return false;
+    var style = window.getComputedStyle(this);
+    if (style.display === 'none' || style.visibility === 'hidden' || style.visibility === 'collapse') return false;
+    var rect = this.getBoundingClientRect();
+    return rect.width > 0 && rect.height > 0;
+}"#;
+
+/// Check whether an element is visible (no waiting)
+#[derive(Args, Debug, Clone, Serialize, Deserialize)]
+#[command(after_help = "\
+Examples:
+  actionbook browser visible \"#error-toast\" --session s1 --tab t1
+  actionbook browser visible @e12 --session s1 --tab t1
+
+Accepts a CSS selector, XPath, or snapshot ref (@eN from snapshot output).
+Prints true when the element exists, has a non-empty box, and is not hidden by
+display/visibility; false otherwise (including when it does not exist).
+Exit codes: 0 when visible, 1 when not, 2 on error.")]
+pub struct Cmd {
+    /// Selector (CSS, XPath, or @ref)
+    pub selector: String,
+    /// Session ID
+    #[arg(long)]
+    #[serde(rename = "session_id")]
+    pub session: String,
+    /// Tab ID
+    #[arg(long)]
+    #[serde(rename = "tab_id")]
+    pub tab: String,
+}
+
+pub const COMMAND_NAME: &str = "browser visible";
+
+pub fn context(cmd: &Cmd, result: &ActionResult) -> Option<ResponseContext> {
+    if let ActionResult::Fatal { code, .. } = result
+        && code == "SESSION_NOT_FOUND"
+    {
+        return None;
+    }
+    let tab_id = if let ActionResult::Fatal { code, .. } = result
+        && code == "TAB_NOT_FOUND"
+    {
+        None
+    } else {
+        Some(cmd.tab.clone())
+    };
+    let (url, title) = match result {
+        ActionResult::Ok { data } => (
+            data.get("__ctx_url")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+            data.get("__ctx_title")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+        ),
+        _ => (None, None),
+    };
+    Some(ResponseContext {
+        session_id: cmd.session.clone(),
+        tab_id,
+        window_id: None,
+        url,
+        title,
+    })
+}
+
+pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
+    let mut ctx = match TabContext::new(registry, &cmd.session, &cmd.tab).await {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    let visible = match find_node(&mut ctx, &cmd.selector).await {
+        Ok(None) => false,
+        Ok(Some(node_id)) => {
+            let object_id = match ctx.resolve_object_id(node_id).await {
+                Ok(id) => id,
+                Err(e) => return e,
+            };
+            match ctx
+                .execute_on_element(
+                    "Runtime.callFunctionOn",
+                    json!({
+                        "objectId": object_id,
+                        "functionDeclaration": IS_VISIBLE_FN,
+                        "returnByValue": true,
+                    }),
+                )
+                .await
+            {
+                Ok(resp) => resp
+                    .pointer("/result/result/value")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false),
+                Err(e) => return cdp_error_to_result(e, "CDP_ERROR"),
+            }
+        }
+        Err(e) => return e,
+    };
+    let url = navigation::get_tab_url(&ctx.cdp, &ctx.target_id).await;
+    let title = navigation::get_tab_title(&ctx.cdp, &ctx.target_id).await;
+
+    ActionResult::ok(json!({
+        "target": { "selector": cmd.selector },
+        "value": visible,
+        "__ctx_url": url,
+        "__ctx_title": title,
+    }))
+}
@@ -0,0 +1,119 @@
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::action_result::ActionResult;
+use crate::browser::session::close;
+use crate::daemon::registry::SharedRegistry;
+use crate::output::ResponseContext;
+
+use super::{profile_sessions, validate_profile_name};
+
+/// Delete a local browser profile
+#[derive(Args, Debug, Clone, Serialize, Deserialize)]
+#[command(after_help = "\
+Examples:
+  actionbook browser profiles delete work
+  actionbook browser profiles delete actionbook --yes
+
+Removes the profile's user-data-dir (cookies, storage, history) and any saved
+settings for it. A profile that a session is running on, or that is the
+configured default profile, is only deleted with --yes; running sessions are
+closed first.")]
+pub struct Cmd {
+    /// Profile name
+    pub name: String,
+    /// Also delete a profile that is in use or is the default
+    #[arg(long)]
+    #[serde(default)]
+    pub yes: bool,
+}
+
+pub const COMMAND_NAME: &str = "browser profiles delete";
+
+pub fn context(_cmd: &Cmd, _result: &ActionResult) -> Option<ResponseContext> {
+    None
+}
+
+pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
+    if let Err(e) = validate_profile_name(&cmd.name) {
+        return ActionResult::fatal("INVALID_ARGUMENT", e);
+    }
+    let profiles_dir = crate::config::profiles_dir();
+    let profile_dir = profiles_dir.join(&cmd.name);
+    if profile_dir.parent() != Some(profiles_dir.as_path()) {
+        return ActionResult::fatal(
+            "INVALID_ARGUMENT",
+            format!("invalid profile name: {}", cmd.name),
+        );
+    }
+    if !profile_dir.is_dir() {
+        return ActionResult::fatal_with_hint(
+            "PROFILE_NOT_FOUND",
+            format!("profile '{}' not found", cmd.name),
+            "run `actionbook browser profiles list` to see available profiles",
+        );
+    }
+
+    let sessions: Vec<String> = {
+        let reg = registry.lock().await;
+        profile_sessions(&reg, &cmd.name)
+            .iter()
+            .map(|s| s.id.as_str().to_string())
+            .collect()
+    };
+    if !cmd.yes {
+        if !sessions.is_empty() {
+            return ActionResult::fatal_with_details(
+                "PROFILE_IN_USE",
+                format!(
+                    "profile '{}' is in use by session {}",
+                    cmd.name,
+                    sessions.join(", ")
+                ),
+                "pass --yes to close those sessions and delete the profile",
+                json!({ "sessions": sessions }),
+            );
+        }
+        if crate::config::load_config().is_ok_and(|c| c.browser.profile_name == cmd.name) {
+            return ActionResult::fatal_with_hint(
+                "PROFILE_IN_USE",
+                format!("profile '{}' is the configured default profile", cmd.name),
+                "pass --yes to delete it anyway; the next `browser start` recreates it empty",
+            );
+        }
+    }
+
+    for session in &sessions {
+        let closed = close::execute(
+            &close::Cmd {
+                session: session.clone(),
+            },
+            registry,
+        )
+        .await;
+        if !closed.is_ok() {
+            return closed;
+        }
+    }
+
+    // Closing a session already removes non-default profile dirs.
+    if profile_dir.exists()
+        && let Err(e) = std::fs::remove_dir_all(&profile_dir)
+    {
+        return ActionResult::fatal(
+            "IO_ERROR",
+            format!("failed to remove {}: {e}", profile_dir.display()),
+        );
+    }
+
+    let mut data = json!({
+        "name": cmd.name,
+        "deleted": true,
+        "closed_sessions": sessions,
+    });
+    if let Err(e) = crate::config::remove_profile_config(&cmd.name) {
+        data["__warnings"] = json!([format!("failed to drop saved settings: {e}")]);
+    }
+    ActionResult::ok(data)
+}
@@ -0,0 +1,83 @@
+use std::path::Path;
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::action_result::ActionResult;
+use crate::daemon::registry::SharedRegistry;
+use crate::output::ResponseContext;
+
+use super::profile_sessions;
+
+/// List local browser profiles
+#[derive(Args, Debug, Clone, Serialize, Deserialize)]
+#[command(after_help = "\
+Examples:
+  actionbook browser profiles list
+  actionbook browser profiles list --json
+
+Returns each profile under ~/.actionbook/profiles with whether a session is
+running on it, that session's CDP port, and when the profile was last used.")]
+pub struct Cmd {}
+
+pub const COMMAND_NAME: &str = "browser profiles list";
+
+pub fn context(_cmd: &Cmd, _result: &ActionResult) -> Option<ResponseContext> {
+    None
+}
+
+pub async fn execute(_cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
+    let dir = crate::config::profiles_dir();
+    let mut names: Vec<String> = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+            .filter_map(|e| e.file_name().into_string().ok())
+            .collect(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => {
+            return ActionResult::fatal(
+                "IO_ERROR",
+                format!("failed to read {}: {e}", dir.display()),
+            );
+        }
+    };
+    names.sort();
+
+    let reg = registry.lock().await;
+    let profiles: Vec<Value> = names
+        .iter()
+        .map(|name| {
+            let sessions = profile_sessions(&reg, name);
+            json!({
+                "name": name,
+                "running": !sessions.is_empty(),
+                "sessions": sessions.iter().map(|s| s.id.as_str()).collect::<Vec<_>>(),
+                "cdp_port": sessions.iter().find_map(|s| s.cdp_port),
+                "last_used": last_used(&dir.join(name)),
+            })
+        })
+        .collect();
+
+    ActionResult::ok(json!({
+        "total_profiles": profiles.len(),
+        "profiles": profiles,
+    }))
+}
+
+/// Chrome rewrites `Local State` while running and on exit, so its mtime is a
+/// good "last used" signal; fall back to the directory itself.
+fn last_used(profile_dir: &Path) -> Option<String> {
+    let modified = std::fs::metadata(profile_dir.join("Local State"))
+        .or_else(|_| std::fs::metadata(profile_dir))
+        .and_then(|m| m.modified())
+        .ok()?;
+    let since = modified.duration_since(std::time::UNIX_EPOCH).ok()?;
+    Some(
+        crate::browser::observation::network_har::unix_secs_to_rfc3339(
+            since.as_secs(),
+            u64::from(since.subsec_millis()),
+        ),
+    )
+}
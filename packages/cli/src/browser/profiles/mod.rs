@@ -0,0 +1,48 @@
+pub mod delete;
+pub mod list;
+
+use std::path::{Component, Path};
+
+use crate::daemon::registry::{SessionEntry, SessionRegistry};
+use crate::types::Mode;
+
+/// Profile names map to directories under `profiles_dir()`, so they must be a
+/// single plain path component: `.`, `..` and anything with a separator would
+/// resolve to the profiles root or outside it. `browser start --profile` and
+/// `browser profiles delete` both check it.
+pub fn validate_profile_name(name: &str) -> Result<(), String> {
+    let mut components = Path::new(name).components();
+    let single_normal = matches!(
+        (components.next(), components.next()),
+        (Some(Component::Normal(c)), None) if c == name
+    );
+    if !single_normal || name.contains('/') || name.contains('\\') || name.contains("..") {
+        return Err(format!("invalid profile name: {name}"));
+    }
+    Ok(())
+}
+
+/// Active local sessions whose Chrome runs on `profile`'s user-data-dir.
+pub fn profile_sessions<'a>(reg: &'a SessionRegistry, profile: &str) -> Vec<&'a SessionEntry> {
+    reg.list()
+        .into_iter()
+        .filter(|s| s.mode == Mode::Local && s.profile == profile && s.status.is_active())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_profile_name_rejects_paths() {
+        assert!(validate_profile_name("work").is_ok());
+        assert!(validate_profile_name("").is_err());
+        assert!(validate_profile_name("../etc").is_err());
+        assert!(validate_profile_name("a/b").is_err());
+        assert!(validate_profile_name("a\\b").is_err());
+        assert!(validate_profile_name(".").is_err());
+        assert!(validate_profile_name("./").is_err());
+        assert!(validate_profile_name("./work").is_err());
+    }
+}
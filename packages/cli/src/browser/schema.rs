@@ -0,0 +1,618 @@
+//! Machine-readable description of every `browser` subcommand, for tool
+//! bridges (e.g. MCP servers) that want to enumerate commands programmatically.
+//!
+//! Derived from the clap tree rather than declared by hand, so it cannot drift
+//! from what the CLI accepts. Every command answers with the same §2.4 JSON
+//! envelope (`--json`); its `data` shape is command-specific and described by
+//! `output_schema`: a full schema for commands with a typed payload, a
+//! generic object schema otherwise.
+
+use std::any::TypeId;
+
+use clap::{Arg, ArgAction, Command, CommandFactory};
+use serde::Serialize;
+use serde_json::{Value, json};
+
+use crate::cli::Cli;
+
+/// Subcommands that describe the CLI itself rather than drive a browser.
+const EXCLUDED_COMMANDS: &[&str] = &["help", "schema"];
+
+/// Top-level payload of `browser schema`.
+#[derive(Debug, Clone, Serialize)]
+pub struct BrowserSchema {
+    pub version: &'static str,
+    /// JSON Schema of the response envelope shared by all tools.
+    pub envelope: Value,
+    pub tools: Vec<ToolSchema>,
+}
+
+/// One invocable command, e.g. `browser click` or `browser logs console`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolSchema {
+    /// CLI words after `actionbook`, e.g. `browser logs console`.
+    pub command: String,
+    /// Tool-safe identifier, e.g. `browser_logs_console`.
+    pub name: String,
+    pub description: String,
+    pub params: Vec<ParamSchema>,
+    /// JSON Schema (`type: object`) over `params`, keyed by param name.
+    pub input_schema: Value,
+    /// JSON Schema of the envelope's `data` on success.
+    pub output_schema: Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ParamSchema {
+    pub name: String,
+    /// `--flag` for options; `None` for positionals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flag: Option<String>,
+    /// 1-based position for positionals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<usize>,
+    #[serde(rename = "type")]
+    pub kind: ParamType,
+    /// Element type of an `array` param.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<ParamType>,
+    pub required: bool,
+    pub description: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub possible_values: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParamType {
+    String,
+    Integer,
+    Number,
+    Boolean,
+    Array,
+}
+
+impl ParamType {
+    /// Scalar type of the values clap's value parser produces for `arg`.
+    fn of_values(arg: &Arg) -> Self {
+        let id = arg.get_value_parser().type_id();
+        let is = |t: TypeId| id == t;
+        if [
+            TypeId::of::<u8>(),
+            TypeId::of::<u16>(),
+            TypeId::of::<u32>(),
+            TypeId::of::<u64>(),
+            TypeId::of::<usize>(),
+            TypeId::of::<i32>(),
+            TypeId::of::<i64>(),
+        ]
+        .into_iter()
+        .any(is)
+        {
+            ParamType::Integer
+        } else if is(TypeId::of::<f64>()) || is(TypeId::of::<f32>()) {
+            ParamType::Number
+        } else if is(TypeId::of::<bool>()) {
+            ParamType::Boolean
+        } else {
+            ParamType::String
+        }
+    }
+}
+
+impl ParamSchema {
+    fn json_schema(&self) -> Value {
+        let mut schema = match self.kind {
+            ParamType::Array => json!({
+                "type": "array",
+                "items": { "type": self.items.unwrap_or(ParamType::String) },
+            }),
+            kind => json!({ "type": kind }),
+        };
+        schema["description"] = json!(self.description);
+        if !self.possible_values.is_empty() {
+            let values = json!(self.possible_values);
+            match self.kind {
+                ParamType::Array => schema["items"]["enum"] = values,
+                _ => schema["enum"] = values,
+            }
+        }
+        schema
+    }
+}
+
+/// Build the schema for all visible `browser` subcommands.
+pub fn browser_schema() -> BrowserSchema {
+    let mut cli = Cli::command();
+    cli.build();
+    let mut tools = Vec::new();
+    if let Some(browser) = cli.find_subcommand("browser") {
+        collect_tools(browser, "browser", &mut tools);
+    }
+    BrowserSchema {
+        version: crate::BUILD_VERSION,
+        envelope: envelope_schema(),
+        tools,
+    }
+}
+
+fn collect_tools(parent: &Command, prefix: &str, tools: &mut Vec<ToolSchema>) {
+    for sub in parent.get_subcommands() {
+        if sub.is_hide_set() || EXCLUDED_COMMANDS.contains(&sub.get_name()) {
+            continue;
+        }
+        let command = format!("{prefix} {}", sub.get_name());
+        if sub.has_subcommands() {
+            collect_tools(sub, &command, tools);
+        } else {
+            tools.push(tool_schema(sub, command));
+        }
+    }
+}
+
+fn tool_schema(cmd: &Command, command: String) -> ToolSchema {
+    let params: Vec<ParamSchema> = cmd
+        .get_arguments()
+        // Global flags (--json, --timeout, ...) belong to the CLI, not the tool.
+        .filter(|arg| !arg.is_hide_set() && !arg.is_global_set())
+        .filter(|arg| !matches!(arg.get_action(), ArgAction::Help | ArgAction::Version))
+        .map(|arg| {
+            let is_flag = matches!(arg.get_action(), ArgAction::SetTrue | ArgAction::SetFalse);
+            let is_array = matches!(arg.get_action(), ArgAction::Append)
+                || arg.get_num_args().is_some_and(|n| n.max_values() > 1);
+            let (kind, items) = match ParamType::of_values(arg) {
+                _ if is_flag => (ParamType::Boolean, None),
+                scalar if is_array => (ParamType::Array, Some(scalar)),
+                scalar => (scalar, None),
+            };
+            let default = arg
+                .get_default_values()
+                .first()
+                .map(|v| v.to_string_lossy().into_owned())
+                .filter(|_| !is_flag);
+            ParamSchema {
+                name: arg.get_id().as_str().to_string(),
+                flag: arg.get_long().map(|l| format!("--{l}")),
+                position: arg.get_index(),
+                kind,
+                items,
+                required: arg.is_required_set(),
+                description: arg.get_help().map(|h| h.to_string()).unwrap_or_default(),
+                possible_values: arg
+                    .get_possible_values()
+                    .iter()
+                    .filter(|v| !v.is_hide_set())
+                    .map(|v| v.get_name().to_string())
+                    .collect(),
+                default,
+            }
+        })
+        .collect();
+
+    let properties: serde_json::Map<String, Value> = params
+        .iter()
+        .map(|p| (p.name.clone(), p.json_schema()))
+        .collect();
+    let required: Vec<&str> = params
+        .iter()
+        .filter(|p| p.required)
+        .map(|p| p.name.as_str())
+        .collect();
+
+    ToolSchema {
+        name: command.replace([' ', '-'], "_"),
+        output_schema: output_schema(&command),
+        command,
+        description: cmd.get_about().map(|a| a.to_string()).unwrap_or_default(),
+        input_schema: json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+        }),
+        params,
+    }
+}
+
+/// `data` schema for `command`. Typed payloads (`InspectResult`,
+/// `SnapshotDiff`) get their full shape, checked against the structs by
+/// tests; every other command gets a generic object.
+fn output_schema(command: &str) -> Value {
+    match command {
+        "browser inspect-point" => {
+            let node = inspected_node_schema();
+            json!({
+                "type": "object",
+                "required": ["point", "element", "parents"],
+                "properties": {
+                    "point": {
+                        "type": ["object", "null"],
+                        "required": ["x", "y"],
+                        "properties": {
+                            "x": { "type": "number" },
+                            "y": { "type": "number" },
+                        },
+                    },
+                    "element": { "anyOf": [node.clone(), { "type": "null" }] },
+                    "parents": { "type": "array", "items": node },
+                    "target": {
+                        "type": "object",
+                        "properties": { "selector": { "type": "string" } },
+                    },
+                },
+            })
+        }
+        "browser snapshot" => {
+            let node = node_entry_schema();
+            json!({
+                "type": "object",
+                "required": ["format", "path", "nodes", "stats"],
+                "properties": {
+                    "format": { "type": "string" },
+                    "path": { "type": "string" },
+                    "nodes": { "type": "array", "items": node.clone() },
+                    "stats": {
+                        "type": "object",
+                        "properties": {
+                            "node_count": { "type": "integer" },
+                            "interactive_count": { "type": "integer" },
+                        },
+                    },
+                    "has_baseline": {
+                        "type": "boolean",
+                        "description": "--diff only: whether a previous snapshot was compared against",
+                    },
+                    "diff": {
+                        "type": "object",
+                        "description": "--diff only: changes since the previous snapshot, by ref",
+                        "required": ["added", "removed", "changed"],
+                        "properties": {
+                            "added": { "type": "array", "items": node.clone() },
+                            "removed": { "type": "array", "items": node.clone() },
+                            "changed": {
+                                "type": "array",
+                                "items": {
+                                    "type": "object",
+                                    "required": ["before", "after"],
+                                    "properties": { "before": node.clone(), "after": node },
+                                },
+                            },
+                        },
+                    },
+                },
+            })
+        }
+        _ => json!({
+            "type": "object",
+            "description": "command-specific result fields; see the command's --help",
+        }),
+    }
+}
+
+/// `ElementState` flags; only `true` flags are present.
+fn element_state_properties() -> serde_json::Map<String, Value> {
+    ["disabled", "required", "focused"]
+        .into_iter()
+        .map(|flag| (flag.to_string(), json!({ "type": "boolean" })))
+        .collect()
+}
+
+/// `snapshot_transform::NodeEntry`.
+fn node_entry_schema() -> Value {
+    let mut properties = element_state_properties();
+    for field in ["ref", "role", "name", "value"] {
+        properties.insert(field.to_string(), json!({ "type": "string" }));
+    }
+    json!({
+        "type": "object",
+        "required": ["ref", "role", "name", "value"],
+        "properties": properties,
+    })
+}
+
+/// `inspect_point::InspectedNode`.
+fn inspected_node_schema() -> Value {
+    let mut properties = element_state_properties();
+    for field in ["role", "name", "selector"] {
+        properties.insert(field.to_string(), json!({ "type": "string" }));
+    }
+    json!({
+        "type": "object",
+        "required": ["role", "name", "selector"],
+        "properties": properties,
+    })
+}
+
+/// JSON Schema of [`crate::output::JsonEnvelope`].
+fn envelope_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["ok", "command", "context", "data", "error", "meta"],
+        "properties": {
+            "ok": { "type": "boolean" },
+            "command": { "type": "string" },
+            "context": {
+                "type": ["object", "null"],
+                "properties": {
+                    "session_id": { "type": "string" },
+                    "tab_id": { "type": "string" },
+                    "window_id": { "type": "string" },
+                    "url": { "type": "string" },
+                    "title": { "type": "string" },
+                },
+            },
+            "data": { "description": "command-specific result; null on failure" },
+            "error": {
+                "type": ["object", "null"],
+                "properties": {
+                    "code": { "type": "string" },
+                    "message": { "type": "string" },
+                    "retryable": { "type": "boolean" },
+                    "details": { "type": "object" },
+                    "hint": { "type": "string" },
+                },
+            },
+            "meta": {
+                "type": "object",
+                "properties": {
+                    "duration_ms": { "type": "integer" },
+                    "warnings": { "type": "array", "items": { "type": "string" } },
+                    "pagination": {},
+                    "truncated": { "type": "boolean" },
+                },
+            },
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tool<'a>(schema: &'a BrowserSchema, command: &str) -> &'a ToolSchema {
+        schema
+            .tools
+            .iter()
+            .find(|t| t.command == command)
+            .unwrap_or_else(|| panic!("missing tool {command}"))
+    }
+
+    #[test]
+    fn browser_schema_lists_leaf_commands_only() {
+        let schema = browser_schema();
+        assert!(schema.tools.iter().all(|t| t.command != "browser help"));
+        assert!(schema.tools.iter().all(|t| t.command != "browser schema"));
+        assert!(schema.tools.iter().all(|t| t.command != "browser logs"));
+        assert_eq!(
+            tool(&schema, "browser logs console").name,
+            "browser_logs_console"
+        );
+    }
+
+    #[test]
+    fn browser_schema_describes_params() {
+        let schema = browser_schema();
+        let click = tool(&schema, "browser click");
+        assert!(!click.description.is_empty());
+
+        let session = click.params.iter().find(|p| p.name == "session").unwrap();
+        assert_eq!(session.flag.as_deref(), Some("--session"));
+        assert!(session.required);
+        assert_eq!(session.kind, ParamType::String);
+        assert!(
+            click
+                .params
+                .iter()
+                .all(|p| p.name != "help" && p.name != "json")
+        );
+
+        let snapshot = tool(&schema, "browser snapshot");
+        let role = snapshot.params.iter().find(|p| p.name == "role").unwrap();
+        assert_eq!(role.kind, ParamType::Array);
+        let interactive = snapshot
+            .params
+            .iter()
+            .find(|p| p.name == "interactive")
+            .unwrap();
+        assert_eq!(interactive.kind, ParamType::Boolean);
+        assert_eq!(
+            snapshot.input_schema["properties"]["interactive"]["type"],
+            "boolean"
+        );
+        assert!(
+            snapshot.input_schema["required"]
+                .as_array()
+                .unwrap()
+                .contains(&json!("session"))
+        );
+    }
+
+    #[test]
+    fn browser_schema_types_numeric_params() {
+        let schema = browser_schema();
+        let snapshot = tool(&schema, "browser snapshot");
+        let depth = snapshot.params.iter().find(|p| p.name == "depth").unwrap();
+        assert_eq!(depth.kind, ParamType::Integer);
+        assert_eq!(
+            snapshot.input_schema["properties"]["depth"]["type"],
+            "integer"
+        );
+
+        let start = tool(&schema, "browser start");
+        let timeout = start
+            .params
+            .iter()
+            .find(|p| p.name == "command_timeout")
+            .unwrap();
+        assert_eq!(timeout.kind, ParamType::Integer);
+        let stealth = start.params.iter().find(|p| p.name == "stealth").unwrap();
+        assert_eq!(stealth.kind, ParamType::Boolean);
+        assert_eq!(stealth.default.as_deref(), Some("true"));
+    }
+
+    #[test]
+    fn browser_schema_describes_typed_outputs() {
+        let schema = browser_schema();
+        let inspect = tool(&schema, "browser inspect-point");
+        let output = &inspect.output_schema;
+        assert_eq!(
+            output["properties"]["point"]["properties"]["x"]["type"],
+            "number"
+        );
+        assert_eq!(
+            output["properties"]["parents"]["items"]["properties"]["selector"]["type"],
+            "string"
+        );
+
+        let snapshot = tool(&schema, "browser snapshot");
+        let diff = &snapshot.output_schema["properties"]["diff"];
+        assert_eq!(
+            diff["properties"]["changed"]["items"]["properties"]["after"]["properties"]["ref"]["type"],
+            "string"
+        );
+
+        assert!(
+            schema
+                .tools
+                .iter()
+                .all(|t| t.output_schema["type"] == "object")
+        );
+        assert!(
+            tool(&schema, "browser click").output_schema["properties"].is_null(),
+            "untyped payloads get the generic object schema"
+        );
+    }
+
+    /// Minimal JSON Schema check covering the keywords the output schemas
+    /// use. Objects with `properties` must not carry undeclared keys, so a
+    /// field added to a payload struct fails until the schema describes it.
+    fn check(schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+        if let Some(options) = schema["anyOf"].as_array() {
+            if options.iter().any(|s| check(s, value, path).is_ok()) {
+                return Ok(());
+            }
+            return Err(format!("{path}: no anyOf branch matches {value}"));
+        }
+        let types: Vec<&str> = match &schema["type"] {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+            _ => return Ok(()),
+        };
+        let actual = match value {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        };
+        let number_ok = actual == "integer" && types.contains(&"number");
+        if !types.contains(&actual) && !number_ok {
+            return Err(format!("{path}: expected {types:?}, got {value}"));
+        }
+        match value {
+            Value::Object(obj) => {
+                for key in schema["required"].as_array().into_iter().flatten() {
+                    let key = key.as_str().unwrap_or_default();
+                    if !obj.contains_key(key) {
+                        return Err(format!("{path}: missing required '{key}'"));
+                    }
+                }
+                if let Some(props) = schema["properties"].as_object() {
+                    for (key, v) in obj {
+                        let sub = props
+                            .get(key)
+                            .ok_or_else(|| format!("{path}: '{key}' not in schema"))?;
+                        check(sub, v, &format!("{path}.{key}"))?;
+                    }
+                }
+            }
+            Value::Array(items) => {
+                for (i, v) in items.iter().enumerate() {
+                    check(&schema["items"], v, &format!("{path}[{i}]"))?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn assert_matches(schema: &Value, value: &Value, path: &str) {
+        if let Err(e) = check(schema, value, path) {
+            panic!("{e}");
+        }
+    }
+
+    #[test]
+    fn output_schemas_match_serialized_payloads() {
+        use crate::browser::observation::inspect_point::{InspectResult, InspectedNode, Point};
+        use crate::browser::observation::snapshot_transform::{
+            ElementState, NodeChange, NodeEntry, SnapshotDiff,
+        };
+
+        let state = ElementState {
+            disabled: true,
+            required: true,
+            focused: true,
+        };
+        let inspected = InspectedNode {
+            role: "button".to_string(),
+            name: "Submit".to_string(),
+            selector: "#submit".to_string(),
+            state,
+        };
+        let schema = browser_schema();
+        let inspect = &tool(&schema, "browser inspect-point").output_schema;
+        for result in [
+            InspectResult {
+                point: Some(Point { x: 10.5, y: 20.0 }),
+                element: Some(inspected.clone()),
+                parents: vec![inspected.clone()],
+            },
+            InspectResult {
+                point: None,
+                element: None,
+                parents: Vec::new(),
+            },
+        ] {
+            assert_matches(inspect, &serde_json::to_value(result).unwrap(), "inspect");
+        }
+
+        let node = NodeEntry {
+            r#ref: "e1".to_string(),
+            role: "checkbox".to_string(),
+            name: "Agree".to_string(),
+            value: "on".to_string(),
+            state,
+        };
+        let diff = SnapshotDiff {
+            added: vec![node.clone()],
+            removed: vec![node.clone()],
+            changed: vec![NodeChange {
+                before: node.clone(),
+                after: node.clone(),
+            }],
+        };
+        let snapshot = &tool(&schema, "browser snapshot").output_schema;
+        assert_matches(
+            &snapshot["properties"]["diff"],
+            &serde_json::to_value(diff).unwrap(),
+            "diff",
+        );
+        assert_matches(
+            &snapshot["properties"]["nodes"],
+            &serde_json::to_value(vec![node]).unwrap(),
+            "nodes",
+        );
+    }
+
+    #[test]
+    fn browser_schema_marks_positionals() {
+        let schema = browser_schema();
+        let goto = tool(&schema, "browser goto");
+        let url = goto.params.iter().find(|p| p.flag.is_none()).unwrap();
+        assert_eq!(url.position, Some(1));
+    }
+}
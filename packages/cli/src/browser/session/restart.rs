@@ -67,6 +67,11 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         cdp,
         chrome_process,
         max_tracked_requests,
+        command_timeout,
+        connect_retries,
+        heartbeat_interval,
+        extension_id,
+        auto_dismiss_dialogs,
     );
     {
         let mut reg = registry.lock().await;
@@ -124,6 +129,11 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         cdp = entry.cdp.take();
         chrome_process = entry.chrome_process.take();
         max_tracked_requests = entry.max_tracked_requests;
+        command_timeout = entry.command_timeout_ms;
+        connect_retries = entry.connect_retries;
+        heartbeat_interval = entry.heartbeat_interval_ms;
+        extension_id = entry.extension_id.clone();
+        auto_dismiss_dialogs = entry.auto_dismiss_dialogs;
 
         reg.clear_session_ref_caches(&cmd.session);
     }
@@ -216,7 +226,12 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         session: None,
         set_session_id: Some(cmd.session.clone()),
         stealth,
+        auto_dismiss_dialogs,
         max_tracked_requests,
+        command_timeout,
+        connect_retries,
+        heartbeat_interval,
+        extension_id,
         provider_env: effective_provider_env,
     };
 
@@ -102,11 +102,41 @@ pub struct Cmd {
     #[arg(long, default_value = "true", action = clap::ArgAction::Set)]
     #[serde(default = "default_stealth")]
     pub stealth: bool,
+    /// Dismiss JavaScript dialogs (alert/confirm/prompt) that no `browser dialog`
+    /// is waiting for, so they can't block the tab (default: true). Pass
+    /// `--auto-dismiss-dialogs false` to leave them open for `browser dialog`.
+    #[arg(long, default_value = "true", action = clap::ArgAction::Set)]
+    #[serde(default = "default_auto_dismiss_dialogs")]
+    pub auto_dismiss_dialogs: bool,
     /// Maximum number of network requests to track per tab (ring buffer capacity).
     /// Must be between 1 and 100000. Default: 500.
     #[arg(long, default_value_t = 500)]
     #[serde(default = "default_max_tracked_requests")]
     pub max_tracked_requests: usize,
+    /// Extension mode only: how long (ms) to wait for the extension to answer
+    /// each command before failing with TIMEOUT, e.g. when the page is frozen.
+    /// Must be between 1 and 600000. Default: 60000.
+    #[arg(long)]
+    #[serde(default)]
+    pub command_timeout: Option<u64>,
+    /// Extension mode only: how many times to retry connecting to the
+    /// extension bridge while its listener is restarting (0 fails at once).
+    /// Must be at most 20. Default: 3.
+    #[arg(long)]
+    #[serde(default)]
+    pub connect_retries: Option<usize>,
+    /// Extension mode only: how often (ms) the bridge pings the extension;
+    /// it is evicted after three unanswered intervals. Applies to the whole
+    /// bridge, so it affects other extension sessions too.
+    /// Must be between 1000 and 300000. Default: 10000.
+    #[arg(long)]
+    #[serde(default)]
+    pub heartbeat_interval: Option<u64>,
+    /// Extension mode only: which connected extension to drive, when more
+    /// than one is connected (ids are listed by `extension status`).
+    #[arg(long)]
+    #[serde(default)]
+    pub extension_id: Option<String>,
     /// Snapshot of provider env vars forwarded from the CLI client to the
     /// daemon (DRIVER_*, HYPERBROWSER_*, BROWSER_USE_*).
     /// The daemon must NOT read these from its own process env — its env was
@@ -126,6 +156,10 @@ fn default_stealth() -> bool {
     true
 }
 
+fn default_auto_dismiss_dialogs() -> bool {
+    true
+}
+
 /// clap value parser for `-p / --provider`.
 ///
 /// Using `PossibleValuesParser` (rather than a `ValueEnum`) keeps the
@@ -214,11 +248,8 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
     let profile_name = cmd.profile.as_deref().unwrap_or(DEFAULT_PROFILE);
     let cdp_endpoint = cmd.cdp_endpoint.as_deref();
 
-    if profile_name.contains('/') || profile_name.contains('\\') || profile_name.contains("..") {
-        return ActionResult::fatal(
-            "INVALID_ARGUMENT",
-            format!("invalid profile name: {profile_name}"),
-        );
+    if let Err(e) = crate::browser::profiles::validate_profile_name(profile_name) {
+        return ActionResult::fatal("INVALID_ARGUMENT", e);
     }
 
     if provider_name.is_some() && cdp_endpoint.is_some() {
@@ -256,6 +287,67 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         );
     }
 
+    if let Some(ms) = cmd.command_timeout {
+        if ms == 0 || ms > 600_000 {
+            return ActionResult::fatal(
+                "INVALID_ARGUMENT",
+                format!("--command-timeout must be between 1 and 600000, got {ms}"),
+            );
+        }
+        if mode != Mode::Extension {
+            return ActionResult::fatal_with_hint(
+                "INVALID_ARGUMENT",
+                "--command-timeout is only supported in extension mode",
+                "add --mode extension, or use the global --timeout to bound a single command",
+            );
+        }
+    }
+
+    if let Some(n) = cmd.connect_retries {
+        if n > 20 {
+            return ActionResult::fatal(
+                "INVALID_ARGUMENT",
+                format!("--connect-retries must be at most 20, got {n}"),
+            );
+        }
+        if mode != Mode::Extension {
+            return ActionResult::fatal_with_hint(
+                "INVALID_ARGUMENT",
+                "--connect-retries is only supported in extension mode",
+                "add --mode extension",
+            );
+        }
+    }
+
+    if let Some(ms) = cmd.heartbeat_interval {
+        let range = crate::daemon::bridge::HEARTBEAT_INTERVAL_RANGE_MS;
+        if !range.contains(&ms) {
+            return ActionResult::fatal(
+                "INVALID_ARGUMENT",
+                format!(
+                    "--heartbeat-interval must be between {} and {}, got {ms}",
+                    range.start(),
+                    range.end()
+                ),
+            );
+        }
+        if mode != Mode::Extension {
+            return ActionResult::fatal_with_hint(
+                "INVALID_ARGUMENT",
+                "--heartbeat-interval is only supported in extension mode",
+                "add --mode extension",
+            );
+        }
+    }
+
+    if cmd.extension_id.is_some() && mode != Mode::Extension {
+        return ActionResult::fatal_with_hint(
+            "INVALID_ARGUMENT",
+            "--extension-id is only supported in extension mode",
+            "add --mode extension",
+        );
+    }
+
     // Parse headers from "KEY:VALUE" strings
     let headers = match parse_headers(&cmd.header) {
         Ok(h) => h,
@@ -796,6 +888,7 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
             .await;
         }
     };
+    cdp.set_auto_dismiss_dialogs(cmd.auto_dismiss_dialogs);
     // Fetch real User-Agent from browser, strip Headless markers for stealth.
     // Only fetched when stealth is enabled; passed to attach() which gates injection on Some(ua).
     let user_agent: Option<String> = if cmd.stealth {
@@ -823,6 +916,24 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
 
     let first_native_id = native_tabs.first().map(|t| t.0.clone()).unwrap_or_default();
 
+    // Restore the viewport saved for this profile (`browser viewport --set`).
+    // Skipped for --cdp-endpoint: that window belongs to someone else.
+    if cdp_endpoint.is_none()
+        && !first_native_id.is_empty()
+        && let Some((width, height)) = crate::config::profile_viewport(profile_name)
+        && let Err(e) = crate::browser::observation::viewport::resize_viewport(
+            &cdp,
+            &first_native_id,
+            width,
+            height,
+        )
+        .await
+    {
+        tracing::warn!(
+            "failed to apply saved viewport {width}x{height} for profile {profile_name}: {e}"
+        );
+    }
+
     // Navigate to open_url after attach so the stealth script is already injected.
     if let Some(url) = &cmd.open_url
         && !first_native_id.is_empty()
@@ -871,6 +982,7 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
     entry.cdp_port = port;
     entry.ws_url = ws_url.clone();
     entry.max_tracked_requests = cmd.max_tracked_requests;
+    entry.auto_dismiss_dialogs = cmd.auto_dismiss_dialogs;
     for (native_id, url, title) in native_tabs {
         entry.push_tab(native_id, url, title);
     }
@@ -1236,6 +1348,7 @@ async fn execute_cloud(
             .await;
         }
     };
+    cdp.set_auto_dismiss_dialogs(cmd.auto_dismiss_dialogs);
 
     // ── Discover tabs via Target.getTargets ──
     let tabs = match discover_tabs_via_cdp(&cdp).await {
@@ -1334,6 +1447,7 @@ async fn execute_cloud(
     entry.cdp_port = None;
     entry.ws_url = ws_url.clone();
     entry.max_tracked_requests = max_tracked_requests;
+    entry.auto_dismiss_dialogs = cmd.auto_dismiss_dialogs;
     for (native_id, url, title) in tabs {
         entry.push_tab(native_id, url, title);
     }
@@ -1385,7 +1499,9 @@ async fn execute_extension(
     profile_name: &str,
     headless: bool,
 ) -> ActionResult {
-    use crate::daemon::bridge::{BRIDGE_PORT, BridgeError, ensure_bridge};
+    use crate::daemon::bridge::{
+        BRIDGE_PORT, BridgeError, bridge_ws_url, connect_retry_delays, ensure_bridge,
+    };
 
     // Lazy bridge: bind 19222 on the first --mode extension call.
     // ensure_bridge is idempotent and recovers from a previous Failed state.
@@ -1428,31 +1544,56 @@ async fn execute_extension(
             return ActionResult::fatal_with_hint("BRIDGE_BIND_FAILED", message, hint);
         }
     };
+    if let Some(ms) = cmd.heartbeat_interval {
+        bridge_state
+            .lock()
+            .await
+            .set_heartbeat_interval(std::time::Duration::from_millis(ms));
+    }
 
     // Bridge is bound; now wait for the Chrome extension's WS handshake to
     // complete (the extension uses exponential-backoff reconnect after a
     // daemon restart so it can take 100ms–2s).
-    let bridge_ws_url = {
-        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
-        loop {
-            {
-                let bs = bridge_state.lock().await;
-                if bs.is_extension_connected() {
-                    break;
-                }
+    // With several extensions connected (e.g. one per Chrome profile) the
+    // caller must pick one via --extension-id.
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+    let extension_id = loop {
+        let ids = bridge_state.lock().await.extension_ids();
+        match (cmd.extension_id.as_deref(), ids.as_slice()) {
+            (Some(wanted), _) if ids.iter().any(|id| id == wanted) => break wanted.to_string(),
+            (None, [only]) => break only.clone(),
+            (None, [_, _, ..]) => {
+                return ActionResult::fatal_with_details(
+                    "EXTENSION_AMBIGUOUS",
+                    format!(
+                        "{} extensions are connected to the bridge: {}",
+                        ids.len(),
+                        ids.join(", ")
+                    ),
+                    "pass --extension-id <id> to choose one (see `actionbook extension status`)",
+                    json!({ "extension_ids": ids }),
+                );
             }
-            if std::time::Instant::now() >= deadline {
-                return ActionResult::fatal_with_hint(
+            _ => {}
+        }
+        if std::time::Instant::now() >= deadline {
+            return match cmd.extension_id.as_deref() {
+                Some(wanted) => ActionResult::fatal_with_details(
+                    "EXTENSION_NOT_CONNECTED",
+                    format!("extension '{wanted}' did not connect to the bridge within 5s"),
+                    "run `actionbook extension status` to list connected extension ids",
+                    json!({ "extension_ids": ids }),
+                ),
+                None => ActionResult::fatal_with_hint(
                     "EXTENSION_NOT_CONNECTED",
                     "no Chrome extension connected to the bridge within 5s",
                     "open chrome://extensions, ensure the Actionbook extension is enabled and its popup shows Connected",
-                );
-            }
-            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                ),
+            };
         }
-
-        format!("ws://127.0.0.1:{BRIDGE_PORT}")
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
     };
+    let bridge_ws_url = bridge_ws_url(BRIDGE_PORT, &extension_id);
 
     // Reserve a session placeholder.
     let effective_set_id = cmd.session.as_deref().or(cmd.set_session_id.as_deref());
@@ -1471,9 +1612,15 @@ async fn execute_extension(
         }
     };
 
-    // Connect CdpSession to bridge (transparent relay to extension).
-    let cdp = match CdpSession::connect_with_config(&bridge_ws_url, &[], cmd.max_tracked_requests)
-        .await
+    // Connect CdpSession to bridge (transparent relay to extension). Retry a
+    // few times so a listener that is briefly restarting doesn't abort the start.
+    let cdp = match CdpSession::connect_with_retry(
+        &bridge_ws_url,
+        &[],
+        cmd.max_tracked_requests,
+        &connect_retry_delays(cmd.connect_retries),
+    )
+    .await
     {
         Ok(c) => c,
         Err(e) => {
@@ -1486,6 +1633,10 @@ async fn execute_extension(
             .await;
         }
     };
+    if let Some(ms) = cmd.command_timeout {
+        cdp.set_command_timeout_ms(ms);
+    }
+    cdp.set_auto_dismiss_dialogs(cmd.auto_dismiss_dialogs);
 
     // Extension-specific tab discovery via Extension.listTabs / Extension.attachTab.
     //
@@ -1623,6 +1774,11 @@ async fn execute_extension(
     entry.cdp_port = None;
     entry.ws_url = bridge_ws_url.clone();
     entry.max_tracked_requests = cmd.max_tracked_requests;
+    entry.command_timeout_ms = cmd.command_timeout;
+    entry.connect_retries = cmd.connect_retries;
+    entry.heartbeat_interval_ms = cmd.heartbeat_interval;
+    entry.auto_dismiss_dialogs = cmd.auto_dismiss_dialogs;
+    entry.extension_id = Some(extension_id);
     for (native_id, url, title) in tabs {
         entry.push_tab(native_id, url, title);
     }
@@ -2038,7 +2194,12 @@ mod provider_start_tests {
             session: session.map(str::to_string),
             set_session_id: set_session_id.map(str::to_string),
             stealth: true,
+            auto_dismiss_dialogs: true,
             max_tracked_requests: 500,
+            command_timeout: None,
+            connect_retries: None,
+            heartbeat_interval: None,
+            extension_id: None,
             provider_env: ProviderEnv::new(),
         }
     }
@@ -2136,7 +2297,12 @@ mod provider_start_tests {
                 session: None,
                 set_session_id: Some("hyp3".to_string()),
                 stealth: true,
+                auto_dismiss_dialogs: true,
                 max_tracked_requests: 500,
+                command_timeout: None,
+                connect_retries: None,
+                heartbeat_interval: None,
+                extension_id: None,
                 provider_env: ProviderEnv::new(),
             },
             &registry,
@@ -2203,7 +2369,12 @@ mod provider_start_tests {
                 session: None,
                 set_session_id: Some("hyp3".to_string()),
                 stealth: true,
+                auto_dismiss_dialogs: true,
                 max_tracked_requests: 500,
+                command_timeout: None,
+                connect_retries: None,
+                heartbeat_interval: None,
+                extension_id: None,
                 provider_env: ProviderEnv::from([
                     ("HYPERBROWSER_API_KEY".to_string(), "hb-key".to_string()),
                     (
@@ -2256,7 +2427,12 @@ mod provider_start_tests {
                 session: None,
                 set_session_id: Some("bs1".to_string()),
                 stealth: true,
+                auto_dismiss_dialogs: true,
                 max_tracked_requests: 500,
+                command_timeout: None,
+                connect_retries: None,
+                heartbeat_interval: None,
+                extension_id: None,
                 provider_env: ProviderEnv::new(),
             },
             &registry,
@@ -2431,6 +2607,29 @@ mod provider_start_tests {
             other => panic!("expected reused ok result, got {other:?}"),
         }
     }
+
+    #[tokio::test]
+    async fn heartbeat_interval_is_bounded_and_extension_only() {
+        let registry = new_shared_registry();
+        let fatal_message = |result: ActionResult| match result {
+            ActionResult::Fatal { code, message, .. } => {
+                assert_eq!(code, "INVALID_ARGUMENT");
+                message
+            }
+            other => panic!("expected INVALID_ARGUMENT, got {other:?}"),
+        };
+
+        let mut cmd = local_cmd(None, None, None);
+        cmd.mode = Some(Mode::Extension);
+        cmd.heartbeat_interval = Some(999);
+        assert!(fatal_message(execute(&cmd, &registry).await).contains("between 1000 and 300000"));
+        cmd.heartbeat_interval = Some(300_001);
+        assert!(fatal_message(execute(&cmd, &registry).await).contains("got 300001"));
+
+        let mut cmd = local_cmd(None, None, None);
+        cmd.heartbeat_interval = Some(5_000);
+        assert!(fatal_message(execute(&cmd, &registry).await).contains("extension mode"));
+    }
 }
 
 /// Set the profile display name in Chrome's Local State and Preferences files
@@ -9,12 +9,13 @@ use crate::output::ResponseContext;
 
 use super::StorageKind;
 
-/// Clear the value for a specific key in a Web Storage object
+/// Clear one key, or the whole Web Storage object when no key is given
 #[derive(Args, Debug, Clone, Serialize, Deserialize)]
 pub struct Cmd {
-    /// Storage key to clear
+    /// Storage key to clear (omit to clear every key)
     #[arg()]
-    pub key: String,
+    #[serde(default)]
+    pub key: Option<String>,
     /// Session ID
     #[arg(long)]
     #[serde(rename = "session_id")]
@@ -73,14 +74,22 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         Err(e) => return e,
     };
 
-    let key_json = serde_json::to_string(&cmd.key).unwrap_or_default();
-    // Same mechanics as delete but returns action: "clear".
-    let js = format!(
-        "(function(){{ var s={}; var had=s.getItem({})!==null?1:0; s.removeItem({}); return had; }})()",
-        cmd.kind.js_object(),
-        key_json,
-        key_json
-    );
+    let js = match cmd.key {
+        // Same mechanics as delete but returns action: "clear".
+        Some(ref key) => {
+            let key_json = serde_json::to_string(key).unwrap_or_default();
+            format!(
+                "(function(){{ var s={}; var had=s.getItem({})!==null?1:0; s.removeItem({}); return had; }})()",
+                cmd.kind.js_object(),
+                key_json,
+                key_json
+            )
+        }
+        None => format!(
+            "(function(){{ var s={}; var n=s.length; s.clear(); return n; }})()",
+            cmd.kind.js_object()
+        ),
+    };
 
     let resp = match cdp
         .execute_on_tab(
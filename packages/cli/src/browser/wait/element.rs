@@ -5,20 +5,21 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 use crate::action_result::ActionResult;
+use crate::browser::element::TabContext;
 use crate::browser::navigation;
-use crate::daemon::cdp_session::get_cdp_and_target;
 use crate::daemon::registry::SharedRegistry;
 use crate::output::ResponseContext;
 
 const DEFAULT_TIMEOUT_MS: u64 = 30_000;
 const POLL_INTERVAL_MS: u64 = 100;
 
-/// Wait for a CSS selector to appear in the DOM
+/// Wait for an element (CSS, XPath, or @ref) to appear in the DOM
 #[derive(Args, Debug, Clone, Serialize, Deserialize)]
 #[command(after_help = "\
 Examples:
   actionbook browser wait element '#loaded' --session s1 --tab t1 --timeout 5000
-  actionbook browser wait element '.spinner[hidden]' --session s1 --tab t1")]
+  actionbook browser wait element '.spinner[hidden]' --session s1 --tab t1
+  actionbook browser wait element \"//button[text()='Submit']\" --session s1 --tab t1")]
 pub struct Cmd {
     /// Selector to wait for (CSS, XPath, or @ref)
     pub selector: String,
@@ -71,44 +72,57 @@ pub fn context(cmd: &Cmd, result: &ActionResult) -> Option<ResponseContext> {
 }
 
 pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
-    let (cdp, target_id) = match get_cdp_and_target(registry, &cmd.session, &cmd.tab).await {
-        Ok(v) => v,
+    let mut ctx = match TabContext::new(registry, &cmd.session, &cmd.tab).await {
+        Ok(c) => c,
         Err(e) => return e,
     };
 
     let timeout_ms = cmd.timeout.unwrap_or(DEFAULT_TIMEOUT_MS);
-    let selector_json = serde_json::to_string(&cmd.selector).unwrap_or_default();
-    let js = format!("!!document.querySelector({selector_json})");
     let start = Instant::now();
 
+    // The document root is fetched once and reused across polls; it is only
+    // re-fetched after the page swaps its document (navigation, reload,
+    // document.open) or a query against it fails for another reason.
+    let mut doc_updates = match ctx.cdp.get_cdp_session_id(&ctx.target_id).await {
+        Some(sid) => Some(ctx.cdp.subscribe_events(&sid, "DOM.documentUpdated").await),
+        None => None,
+    };
+    let mut root_id: Option<i64> = None;
+
     loop {
-        let resp = cdp
-            .execute_on_tab(
-                &target_id,
-                "Runtime.evaluate",
-                json!({ "expression": js, "returnByValue": true }),
-            )
-            .await;
-
-        if let Ok(v) = resp {
-            let found = v
-                .pointer("/result/result/value")
-                .and_then(|v| v.as_bool())
-                .unwrap_or(false);
-            if found {
-                let elapsed_ms = start.elapsed().as_millis() as u64;
-                let url = navigation::get_tab_url(&cdp, &target_id).await;
-                let title = navigation::get_tab_title(&cdp, &target_id).await;
-                return ActionResult::ok(json!({
-                    "kind": "element",
-                    "satisfied": true,
-                    "elapsed_ms": elapsed_ms,
-                    "observed_value": { "selector": cmd.selector },
-                    "__ctx_url": url,
-                    "__ctx_title": title,
-                }));
+        if let Some(rx) = doc_updates.as_mut() {
+            while rx.try_recv().is_ok() {
+                root_id = None;
             }
         }
+        if root_id.is_none() {
+            root_id = ctx.document_root().await.ok();
+        }
+
+        // Resolve through TabContext so CSS, XPath and @ref selectors all work.
+        // Any failure (not found yet, document swapped mid-navigation) just polls again.
+        let resolved = match root_id {
+            Some(root) => ctx.resolve_node_in(&cmd.selector, root).await,
+            None => Err(ActionResult::fatal("CDP_ERROR", "document not available")),
+        };
+        if let Err(ActionResult::Fatal { code, .. }) = &resolved
+            && code != "ELEMENT_NOT_FOUND"
+        {
+            root_id = None;
+        }
+        if resolved.is_ok() {
+            let elapsed_ms = start.elapsed().as_millis() as u64;
+            let url = navigation::get_tab_url(&ctx.cdp, &ctx.target_id).await;
+            let title = navigation::get_tab_title(&ctx.cdp, &ctx.target_id).await;
+            return ActionResult::ok(json!({
+                "kind": "element",
+                "satisfied": true,
+                "elapsed_ms": elapsed_ms,
+                "observed_value": { "selector": cmd.selector },
+                "__ctx_url": url,
+                "__ctx_title": title,
+            }));
+        }
 
         let elapsed = start.elapsed().as_millis() as u64;
         if elapsed >= timeout_ms {
@@ -125,3 +139,87 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
         tokio::time::sleep(Duration::from_millis(POLL_INTERVAL_MS)).await;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::cdp_session::CdpSession;
+    use crate::daemon::registry::{self, SessionEntry, SessionState};
+    use crate::types::{Mode, SessionId};
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::Message;
+
+    /// The element shows up on the 4th poll; the page swaps its document
+    /// after the 2nd. Only that swap may trigger a second `DOM.getDocument`.
+    #[tokio::test]
+    async fn polls_reuse_document_until_it_is_updated() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let (mut writer, mut reader) = ws.split();
+            let (mut get_document, mut queries) = (0, 0);
+            while let Some(Ok(raw)) = reader.next().await {
+                let Message::Text(t) = raw else { continue };
+                let msg: serde_json::Value = serde_json::from_str(t.as_ref()).unwrap();
+                let result = match msg["method"].as_str().unwrap() {
+                    "DOM.getDocument" => {
+                        get_document += 1;
+                        json!({ "root": { "nodeId": get_document } })
+                    }
+                    "DOM.querySelector" => {
+                        queries += 1;
+                        if queries == 2 {
+                            let event = json!({
+                                "method": "DOM.documentUpdated",
+                                "tabId": 100,
+                                "params": {},
+                            });
+                            writer
+                                .send(Message::Text(event.to_string().into()))
+                                .await
+                                .unwrap();
+                        }
+                        json!({ "nodeId": if queries == 4 { 7 } else { 0 } })
+                    }
+                    _ => json!({}),
+                };
+                let reply = json!({ "id": msg["id"], "result": result }).to_string();
+                if writer.send(Message::Text(reply.into())).await.is_err() {
+                    break;
+                }
+            }
+            (get_document, queries)
+        });
+
+        let cdp = CdpSession::connect(&url).await.unwrap();
+        cdp.register_extension_tab("100").await;
+        let registry = registry::new_shared_registry();
+        {
+            let mut entry = SessionEntry::starting(
+                SessionId::new_unchecked("s1"),
+                Mode::Extension,
+                false,
+                true,
+                "default".to_string(),
+            );
+            entry.status = SessionState::Running;
+            entry.cdp = Some(cdp.clone());
+            entry.push_tab("100".to_string(), String::new(), String::new());
+            registry.lock().await.insert(entry);
+        }
+
+        let cmd = Cmd {
+            selector: "#ready".to_string(),
+            session: "s1".to_string(),
+            tab: "t1".to_string(),
+            timeout: Some(5_000),
+        };
+        let result = execute(&cmd, &registry).await;
+        assert!(result.is_ok(), "wait element failed: {result:?}");
+        cdp.close().await;
+        assert_eq!(server.await.unwrap(), (2, 4));
+    }
+}
@@ -13,7 +13,7 @@ use crate::output::ResponseContext;
 const DEFAULT_TIMEOUT_MS: u64 = 30_000;
 const POLL_INTERVAL_MS: u64 = 100;
 /// Both the load gate and the post-start network gate must be satisfied
-/// continuously for this long before declaring idle.
+/// continuously for this long before declaring idle (override with `--idle`).
 const IDLE_QUIET_MS: u64 = 500;
 
 /// Wait for the page to settle: readyState=complete, images loaded, and any
@@ -30,13 +30,15 @@ const IDLE_QUIET_MS: u64 = 500;
 #[command(after_help = "\
 Examples:
   actionbook browser wait network-idle --session s1 --tab t1 --timeout 10000
+  actionbook browser wait network-idle --idle 1500 --session s1 --tab t1
 
 Notes:
   Only tracks fetch/XHR requests started after the command begins.
   Pre-existing background connections (SSE, WebSocket, in-flight fetches)
   are ignored and do not block.
   Intended as an agent-friendly settle signal, not a guarantee that all
-  background activity has stopped.")]
+  background activity has stopped.
+  Raise --idle for SPA route changes that fetch in several bursts.")]
 pub struct Cmd {
     /// Session ID
     #[arg(long)]
@@ -49,6 +51,10 @@ pub struct Cmd {
     /// Timeout in milliseconds (default 30000)
     #[arg(long)]
     pub timeout: Option<u64>,
+    /// Quiet window in milliseconds before the page counts as idle (default 500)
+    #[arg(long)]
+    #[serde(default)]
+    pub idle: Option<u64>,
 }
 
 pub const COMMAND_NAME: &str = "browser wait network-idle";
@@ -93,6 +99,7 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
     };
 
     let timeout_ms = cmd.timeout.unwrap_or(DEFAULT_TIMEOUT_MS);
+    let idle_ms = cmd.idle.unwrap_or(IDLE_QUIET_MS);
     // Edge trigger: only block on requests whose requestWillBeSent fires
     // strictly after this instant. Any entries already in the tab's pending
     // map at this point are pre-existing and must not block.
@@ -168,7 +175,7 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
                 quiet_start = Some(Instant::now());
             }
             let quiet_elapsed_ms = quiet_start.unwrap().elapsed().as_millis() as u64;
-            if quiet_elapsed_ms >= IDLE_QUIET_MS {
+            if quiet_elapsed_ms >= idle_ms {
                 let elapsed_ms = start.elapsed().as_millis() as u64;
                 let url = navigation::get_tab_url(&cdp, &target_id).await;
                 let title = navigation::get_tab_title(&cdp, &target_id).await;
@@ -179,6 +186,7 @@ pub async fn execute(cmd: &Cmd, registry: &SharedRegistry) -> ActionResult {
                     "observed_value": {
                         "idle": true,
                         "pending": pending,
+                        "idle_ms": idle_ms,
                     },
                     "__ctx_url": url,
                     "__ctx_title": title,
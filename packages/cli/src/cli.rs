@@ -2,7 +2,9 @@ use clap::{Args, Parser, Subcommand};
 
 use crate::action::Action;
 use crate::action_result::ActionResult;
-use crate::browser::{cookies, interaction, navigation, observation, session, storage, tab, wait};
+use crate::browser::{
+    cookies, emulation, interaction, navigation, observation, profiles, session, storage, tab, wait,
+};
 use crate::output::ResponseContext;
 use crate::setup;
 
@@ -17,7 +19,11 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub json: bool,
 
-    /// Timeout in milliseconds
+    /// Indent JSON output for reading (compact by default)
+    #[arg(long, global = true)]
+    pub pretty: bool,
+
+    /// Upper bound for the whole command, in milliseconds
     #[arg(long, global = true)]
     pub timeout: Option<u64>,
 
@@ -138,10 +144,12 @@ pub struct TabArgs {
 pub enum BrowserCommands {
     /// Show browser help
     Help,
+    /// Print a JSON schema of every browser command (for tool bridges)
+    Schema,
 
     // ── Session lifecycle ──────────────────────────────────────
     /// Start or attach a browser session
-    Start(session::start::Cmd),
+    Start(Box<session::start::Cmd>),
     /// List all active sessions
     ListSessions(session::list::Cmd),
     /// Show session status
@@ -151,6 +159,11 @@ pub enum BrowserCommands {
     Close(session::close::Cmd),
     /// Restart a session
     Restart(session::restart::Cmd),
+    /// List or delete local browser profiles
+    Profiles {
+        #[command(subcommand)]
+        command: ProfilesCommands,
+    },
 
     // ── Tab management ─────────────────────────────────────────
     /// List tabs in a session
@@ -178,10 +191,7 @@ Examples:
   actionbook browser forward --session s1 --tab t1")]
     Forward(TabArgs),
     /// Reload page
-    #[command(after_help = "\
-Examples:
-  actionbook browser reload --session s1 --tab t1")]
-    Reload(TabArgs),
+    Reload(navigation::reload::Cmd),
 
     // ── Observation ────────────────────────────────────────────
     /// Capture accessibility snapshots for multiple tabs
@@ -194,13 +204,17 @@ Examples:
     Url(observation::url::Cmd),
     /// Get viewport dimensions
     Viewport(observation::viewport::Cmd),
+    /// Emulate a device or set the viewport size
+    Emulate(emulation::emulate::Cmd),
+    /// Throttle the tab's network (slow-3g, fast-3g, offline, none)
+    Throttle(emulation::throttle::Cmd),
     /// Read element or page HTML
     Html(observation::html::Cmd),
     /// Read element or page text
     Text(observation::text::Cmd),
     /// Read element value
     Value(observation::value::Cmd),
-    /// Read a named element attribute
+    /// Read a named element attribute or DOM property
     Attr(observation::attr::Cmd),
     /// Read all attributes on an element
     Attrs(observation::attrs::Cmd),
@@ -212,6 +226,10 @@ Examples:
     Describe(observation::describe::Cmd),
     /// Get element state
     State(observation::state::Cmd),
+    /// Check whether an element exists (exit 1 if not)
+    Exists(observation::exists::Cmd),
+    /// Check whether an element is visible (exit 1 if not)
+    Visible(observation::visible::Cmd),
     /// Query elements with cardinality constraints
     Query(observation::query::Cmd),
     /// Inspect element at coordinates
@@ -288,6 +306,8 @@ Examples:
     Drag(interaction::drag::Cmd),
     /// Upload files to a file input
     Upload(interaction::upload::Cmd),
+    /// Accept or dismiss a JavaScript dialog
+    Dialog(interaction::dialog::Cmd),
     /// Move the mouse to absolute coordinates
     MouseMove(interaction::mouse_move::Cmd),
     /// Get the current cursor position
@@ -355,6 +375,19 @@ pub enum CookiesCommands {
     Delete(cookies::delete::Cmd),
     /// Clear cookies (optionally filtered by domain)
     Clear(cookies::clear::Cmd),
+    /// Export cookies to a JSON file
+    Export(cookies::export::Cmd),
+    /// Import cookies from a JSON file
+    Import(cookies::import::Cmd),
+}
+
+#[derive(Subcommand, Debug, Clone)]
+#[command(disable_help_subcommand = true)]
+pub enum ProfilesCommands {
+    /// List local profiles with their running status
+    List(profiles::list::Cmd),
+    /// Delete a local profile's user-data-dir
+    Delete(profiles::delete::Cmd),
 }
 
 #[derive(Subcommand, Debug, Clone)]
@@ -392,14 +425,16 @@ Examples:
 
 Removes the key entirely. No-op if the key does not exist.")]
     Delete(StorageKeyArgs),
-    /// Clear the value for a key
+    /// Clear one key, or every key when none is given
     #[command(after_help = "\
 Examples:
   actionbook browser local-storage clear cache_key --session s1 --tab t1
   actionbook browser session-storage clear pref --session s1 --tab t1
+  actionbook browser local-storage clear --session s1 --tab t1
 
-Removes the key from storage. Returns affected count (1 if existed, 0 if not).")]
-    Clear(StorageKeyArgs),
+With a key, removes it and returns affected count (1 if existed, 0 if not).
+Without a key, empties the storage and returns how many keys were removed.")]
+    Clear(StorageClearArgs),
 }
 
 #[derive(Args, Debug, Clone)]
@@ -419,6 +454,15 @@ pub struct StorageKeyArgs {
     pub tab: String,
 }
 
+#[derive(Args, Debug, Clone)]
+pub struct StorageClearArgs {
+    pub key: Option<String>,
+    #[arg(long)]
+    pub session: String,
+    #[arg(long)]
+    pub tab: String,
+}
+
 #[derive(Args, Debug, Clone)]
 pub struct StorageSetArgs {
     pub key: String,
@@ -433,12 +477,16 @@ impl BrowserCommands {
     /// Convert to wire Action. Returns None for unimplemented commands.
     pub fn to_action(&self) -> Option<Action> {
         Some(match self {
-            Self::Help => return None,
-            Self::Start(cmd) => Action::StartSession(cmd.clone()),
+            Self::Help | Self::Schema => return None,
+            Self::Start(cmd) => Action::StartSession((**cmd).clone()),
             Self::ListSessions(cmd) => Action::ListSessions(cmd.clone()),
             Self::Status(cmd) => Action::SessionStatus(cmd.clone()),
             Self::Close(cmd) => Action::Close(cmd.clone()),
             Self::Restart(cmd) => Action::Restart(cmd.clone()),
+            Self::Profiles { command } => match command {
+                ProfilesCommands::List(cmd) => Action::ProfilesList(cmd.clone()),
+                ProfilesCommands::Delete(cmd) => Action::ProfilesDelete(cmd.clone()),
+            },
             Self::ListTabs(cmd) => Action::ListTabs(cmd.clone()),
             Self::NewTab(cmd) => Action::NewTab(cmd.clone()),
             Self::BatchNewTab(cmd) => Action::BatchOpen(cmd.clone()),
@@ -452,15 +500,14 @@ impl BrowserCommands {
                 session: a.session.clone(),
                 tab: a.tab.clone(),
             }),
-            Self::Reload(a) => Action::Reload(navigation::reload::Cmd {
-                session: a.session.clone(),
-                tab: a.tab.clone(),
-            }),
+            Self::Reload(cmd) => Action::Reload(cmd.clone()),
             Self::BatchSnapshot(cmd) => Action::BatchSnapshot(cmd.clone()),
             Self::Snapshot(cmd) => Action::Snapshot(cmd.clone()),
             Self::Title(cmd) => Action::Title(cmd.clone()),
             Self::Url(cmd) => Action::Url(cmd.clone()),
             Self::Viewport(cmd) => Action::Viewport(cmd.clone()),
+            Self::Emulate(cmd) => Action::Emulate(cmd.clone()),
+            Self::Throttle(cmd) => Action::Throttle(cmd.clone()),
             Self::Html(cmd) => Action::Html(cmd.clone()),
             Self::Text(cmd) => Action::Text(cmd.clone()),
             Self::Value(cmd) => Action::Value(cmd.clone()),
@@ -470,6 +517,8 @@ impl BrowserCommands {
             Self::Styles(cmd) => Action::Styles(cmd.clone()),
             Self::Describe(cmd) => Action::Describe(cmd.clone()),
             Self::State(cmd) => Action::State(cmd.clone()),
+            Self::Exists(cmd) => Action::Exists(cmd.clone()),
+            Self::Visible(cmd) => Action::Visible(cmd.clone()),
             Self::Query(cmd) => Action::Query(cmd.clone()),
             Self::InspectPoint(cmd) => Action::InspectPoint(cmd.clone()),
             Self::Pdf(cmd) => Action::Pdf(cmd.clone()),
@@ -479,6 +528,21 @@ impl BrowserCommands {
                 CookiesCommands::Set(cmd) => Action::CookiesSet(cmd.clone()),
                 CookiesCommands::Delete(cmd) => Action::CookiesDelete(cmd.clone()),
                 CookiesCommands::Clear(cmd) => Action::CookiesClear(cmd.clone()),
+                // Resolve file paths against the CLI's CWD, not the daemon's.
+                CookiesCommands::Export(cmd) => {
+                    let mut cmd = cmd.clone();
+                    if let Ok(abs) = std::path::absolute(&cmd.path) {
+                        cmd.path = abs.to_string_lossy().into_owned();
+                    }
+                    Action::CookiesExport(cmd)
+                }
+                CookiesCommands::Import(cmd) => {
+                    let mut cmd = cmd.clone();
+                    if let Ok(abs) = std::path::absolute(&cmd.path) {
+                        cmd.path = abs.to_string_lossy().into_owned();
+                    }
+                    Action::CookiesImport(cmd)
+                }
             },
             Self::LocalStorage { command } => {
                 storage_to_action(command, storage::StorageKind::Local)
@@ -530,6 +594,7 @@ impl BrowserCommands {
             Self::Select(cmd) => Action::Select(cmd.clone()),
             Self::Drag(cmd) => Action::Drag(cmd.clone()),
             Self::Upload(cmd) => Action::Upload(cmd.clone()),
+            Self::Dialog(cmd) => Action::Dialog(cmd.clone()),
             Self::MouseMove(cmd) => Action::MouseMove(cmd.clone()),
             Self::CursorPosition(cmd) => Action::CursorPosition(cmd.clone()),
             Self::Scroll(cmd) => Action::Scroll(cmd.clone()),
@@ -540,11 +605,16 @@ impl BrowserCommands {
     pub fn command_name(&self) -> &str {
         match self {
             Self::Help => "help",
+            Self::Schema => "browser schema",
             Self::Start(_) => session::start::COMMAND_NAME,
             Self::ListSessions(_) => session::list::COMMAND_NAME,
             Self::Status(_) => session::status::COMMAND_NAME,
             Self::Close(_) => session::close::COMMAND_NAME,
             Self::Restart(_) => session::restart::COMMAND_NAME,
+            Self::Profiles { command } => match command {
+                ProfilesCommands::List(_) => profiles::list::COMMAND_NAME,
+                ProfilesCommands::Delete(_) => profiles::delete::COMMAND_NAME,
+            },
             Self::ListTabs(_) => tab::list::COMMAND_NAME,
             Self::NewTab(_) => tab::open::COMMAND_NAME,
             Self::BatchNewTab(_) => tab::batch_open::COMMAND_NAME,
@@ -552,12 +622,14 @@ impl BrowserCommands {
             Self::Goto(_) => navigation::goto::COMMAND_NAME,
             Self::Back(_) => "browser back",
             Self::Forward(_) => "browser forward",
-            Self::Reload(_) => "browser reload",
+            Self::Reload(_) => navigation::reload::COMMAND_NAME,
             Self::BatchSnapshot(_) => observation::batch_snapshot::COMMAND_NAME,
             Self::Snapshot(_) => observation::snapshot::COMMAND_NAME,
             Self::Title(_) => observation::title::COMMAND_NAME,
             Self::Url(_) => observation::url::COMMAND_NAME,
             Self::Viewport(_) => observation::viewport::COMMAND_NAME,
+            Self::Emulate(_) => emulation::emulate::COMMAND_NAME,
+            Self::Throttle(_) => emulation::throttle::COMMAND_NAME,
             Self::Html(_) => observation::html::COMMAND_NAME,
             Self::Text(_) => observation::text::COMMAND_NAME,
             Self::Value(_) => observation::value::COMMAND_NAME,
@@ -567,6 +639,8 @@ impl BrowserCommands {
             Self::Styles(_) => observation::styles::COMMAND_NAME,
             Self::Describe(_) => observation::describe::COMMAND_NAME,
             Self::State(_) => observation::state::COMMAND_NAME,
+            Self::Exists(_) => observation::exists::COMMAND_NAME,
+            Self::Visible(_) => observation::visible::COMMAND_NAME,
             Self::Query(_) => observation::query::COMMAND_NAME,
             Self::InspectPoint(_) => observation::inspect_point::COMMAND_NAME,
             Self::Pdf(_) => observation::pdf::COMMAND_NAME,
@@ -576,6 +650,8 @@ impl BrowserCommands {
                 CookiesCommands::Set(_) => cookies::set::COMMAND_NAME,
                 CookiesCommands::Delete(_) => cookies::delete::COMMAND_NAME,
                 CookiesCommands::Clear(_) => cookies::clear::COMMAND_NAME,
+                CookiesCommands::Export(_) => cookies::export::COMMAND_NAME,
+                CookiesCommands::Import(_) => cookies::import::COMMAND_NAME,
             },
             Self::LocalStorage { command } => {
                 storage_command_name(command, storage::StorageKind::Local)
@@ -613,6 +689,7 @@ impl BrowserCommands {
             Self::Select(_) => interaction::select::COMMAND_NAME,
             Self::Drag(_) => interaction::drag::COMMAND_NAME,
             Self::Upload(_) => interaction::upload::COMMAND_NAME,
+            Self::Dialog(_) => interaction::dialog::COMMAND_NAME,
             Self::MouseMove(_) => interaction::mouse_move::COMMAND_NAME,
             Self::CursorPosition(_) => interaction::cursor_position::COMMAND_NAME,
             Self::Scroll(_) => interaction::scroll::COMMAND_NAME,
@@ -622,12 +699,16 @@ impl BrowserCommands {
     /// Build response context from command args and result.
     pub fn context(&self, result: &ActionResult) -> Option<ResponseContext> {
         match self {
-            Self::Help => None,
+            Self::Help | Self::Schema => None,
             Self::Start(cmd) => session::start::context(cmd, result),
             Self::ListSessions(cmd) => session::list::context(cmd, result),
             Self::Status(cmd) => session::status::context(cmd, result),
             Self::Close(cmd) => session::close::context(cmd, result),
             Self::Restart(cmd) => session::restart::context(cmd, result),
+            Self::Profiles { command } => match command {
+                ProfilesCommands::List(cmd) => profiles::list::context(cmd, result),
+                ProfilesCommands::Delete(cmd) => profiles::delete::context(cmd, result),
+            },
             Self::ListTabs(cmd) => tab::list::context(cmd, result),
             Self::NewTab(cmd) => tab::open::context(cmd, result),
             Self::BatchNewTab(cmd) => tab::batch_open::context(cmd, result),
@@ -638,6 +719,8 @@ impl BrowserCommands {
             Self::Title(cmd) => observation::title::context(cmd, result),
             Self::Url(cmd) => observation::url::context(cmd, result),
             Self::Viewport(cmd) => observation::viewport::context(cmd, result),
+            Self::Emulate(cmd) => emulation::emulate::context(cmd, result),
+            Self::Throttle(cmd) => emulation::throttle::context(cmd, result),
             Self::Html(cmd) => observation::html::context(cmd, result),
             Self::Text(cmd) => observation::text::context(cmd, result),
             Self::Value(cmd) => observation::value::context(cmd, result),
@@ -647,6 +730,8 @@ impl BrowserCommands {
             Self::Styles(cmd) => observation::styles::context(cmd, result),
             Self::Describe(cmd) => observation::describe::context(cmd, result),
             Self::State(cmd) => observation::state::context(cmd, result),
+            Self::Exists(cmd) => observation::exists::context(cmd, result),
+            Self::Visible(cmd) => observation::visible::context(cmd, result),
             Self::Query(cmd) => observation::query::context(cmd, result),
             Self::InspectPoint(cmd) => observation::inspect_point::context(cmd, result),
             Self::Pdf(cmd) => observation::pdf::context(cmd, result),
@@ -656,6 +741,8 @@ impl BrowserCommands {
                 CookiesCommands::Set(cmd) => cookies::set::context(cmd, result),
                 CookiesCommands::Delete(cmd) => cookies::delete::context(cmd, result),
                 CookiesCommands::Clear(cmd) => cookies::clear::context(cmd, result),
+                CookiesCommands::Export(cmd) => cookies::export::context(cmd, result),
+                CookiesCommands::Import(cmd) => cookies::import::context(cmd, result),
             },
             Self::LocalStorage { command } => {
                 storage_context(command, storage::StorageKind::Local, result)
@@ -700,13 +787,7 @@ impl BrowserCommands {
                 },
                 result,
             ),
-            Self::Reload(a) => navigation::reload::context(
-                &navigation::reload::Cmd {
-                    session: a.session.clone(),
-                    tab: a.tab.clone(),
-                },
-                result,
-            ),
+            Self::Reload(cmd) => navigation::reload::context(cmd, result),
             Self::Click(cmd) => interaction::click::context(cmd, result),
             Self::BatchClick(cmd) => interaction::batch_click::context(cmd, result),
             Self::Hover(cmd) => interaction::hover::context(cmd, result),
@@ -717,6 +798,7 @@ impl BrowserCommands {
             Self::Select(cmd) => interaction::select::context(cmd, result),
             Self::Drag(cmd) => interaction::drag::context(cmd, result),
             Self::Upload(cmd) => interaction::upload::context(cmd, result),
+            Self::Dialog(cmd) => interaction::dialog::context(cmd, result),
             Self::MouseMove(cmd) => interaction::mouse_move::context(cmd, result),
             Self::CursorPosition(cmd) => interaction::cursor_position::context(cmd, result),
             Self::Scroll(cmd) => interaction::scroll::context(cmd, result),
@@ -1099,6 +1181,26 @@ mod tests {
         assert!(cli.is_ok(), "browser drag command should parse");
     }
 
+    #[test]
+    fn try_parse_from_accepts_click_by_text() {
+        let base = ["actionbook", "browser", "click"];
+        let tail = ["--session", "session-1", "--tab", "tab-1"];
+        let parse = |args: &[&str]| {
+            let mut argv = base.to_vec();
+            argv.extend_from_slice(args);
+            argv.extend_from_slice(&tail);
+            Cli::try_parse_from(argv)
+        };
+
+        assert!(parse(&["--text", "Sign in"]).is_ok());
+        assert!(parse(&["--text", "Sign in", "--exact"]).is_ok());
+        assert!(
+            parse(&["#btn", "--text", "Sign in"]).is_err(),
+            "--text conflicts with selectors"
+        );
+        assert!(parse(&[]).is_err(), "selector or --text is required");
+    }
+
     #[test]
     fn try_parse_from_accepts_browser_upload_command() {
         let cli = Cli::try_parse_from([
@@ -1116,6 +1218,117 @@ mod tests {
         assert!(cli.is_ok(), "browser upload command should parse");
     }
 
+    #[test]
+    fn try_parse_from_accepts_storage_clear_with_and_without_key() {
+        for args in [&["clear", "cache_key"][..], &["clear"][..]] {
+            let mut argv = vec!["actionbook", "browser", "local-storage"];
+            argv.extend_from_slice(args);
+            argv.extend_from_slice(&["--session", "session-1", "--tab", "tab-1"]);
+            let cli = Cli::try_parse_from(argv).expect("storage clear should parse");
+            let Some(Commands::Browser {
+                command: BrowserCommands::LocalStorage { command },
+            }) = cli.command
+            else {
+                panic!("expected local-storage command");
+            };
+            let StorageSubCommands::Clear(a) = command else {
+                panic!("expected clear subcommand");
+            };
+            assert_eq!(a.key.as_deref(), args.get(1).copied());
+        }
+    }
+
+    #[test]
+    fn try_parse_from_accepts_browser_emulate_command() {
+        let cli = Cli::try_parse_from([
+            "actionbook",
+            "browser",
+            "emulate",
+            "iPhone 14",
+            "--session",
+            "session-1",
+            "--tab",
+            "tab-1",
+        ]);
+        assert!(cli.is_ok(), "browser emulate <device> should parse");
+
+        let cli = Cli::try_parse_from([
+            "actionbook",
+            "browser",
+            "emulate",
+            "--reset",
+            "--width",
+            "800",
+            "--session",
+            "session-1",
+            "--tab",
+            "tab-1",
+        ]);
+        assert!(
+            cli.is_err(),
+            "browser emulate --reset should conflict with metrics flags"
+        );
+    }
+
+    #[test]
+    fn try_parse_from_accepts_browser_throttle_presets() {
+        for preset in ["slow-3g", "fast-3g", "offline", "none"] {
+            let cli = Cli::try_parse_from([
+                "actionbook",
+                "browser",
+                "throttle",
+                preset,
+                "--session",
+                "session-1",
+                "--tab",
+                "tab-1",
+            ]);
+            assert!(cli.is_ok(), "browser throttle {preset} should parse");
+        }
+
+        let cli = Cli::try_parse_from([
+            "actionbook",
+            "browser",
+            "throttle",
+            "dialup",
+            "--session",
+            "session-1",
+            "--tab",
+            "tab-1",
+        ]);
+        assert!(cli.is_err(), "unknown throttle preset should be rejected");
+    }
+
+    #[test]
+    fn try_parse_from_accepts_browser_dialog_command() {
+        let cli = Cli::try_parse_from([
+            "actionbook",
+            "browser",
+            "dialog",
+            "accept",
+            "--text",
+            "Alice",
+            "--session",
+            "session-1",
+            "--tab",
+            "tab-1",
+        ]);
+
+        assert!(cli.is_ok(), "browser dialog command should parse");
+
+        let cli = Cli::try_parse_from([
+            "actionbook",
+            "browser",
+            "dialog",
+            "ignore",
+            "--session",
+            "session-1",
+            "--tab",
+            "tab-1",
+        ]);
+        assert!(cli.is_err(), "browser dialog should reject unknown actions");
+    }
+
     #[test]
     fn try_parse_from_rejects_browser_upload_without_files() {
         let cli = Cli::try_parse_from([
@@ -1296,6 +1509,155 @@ mod tests {
         }
     }
 
+    #[test]
+    fn try_parse_from_parses_network_idle_window() {
+        let cli = Cli::try_parse_from([
+            "actionbook",
+            "browser",
+            "wait",
+            "network-idle",
+            "--idle",
+            "1500",
+            "--session",
+            "s1",
+            "--tab",
+            "t1",
+        ])
+        .expect("parse wait network-idle --idle");
+
+        match cli.command {
+            Some(Commands::Browser {
+                command:
+                    BrowserCommands::Wait {
+                        command: WaitCommands::NetworkIdle(cmd),
+                    },
+            }) => {
+                assert_eq!(cmd.idle, Some(1500));
+                assert_eq!(cmd.timeout, None);
+            }
+            other => panic!("expected browser wait network-idle command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn try_parse_from_rejects_screenshot_clip_with_selector() {
+        let err = Cli::try_parse_from([
+            "actionbook",
+            "browser",
+            "screenshot",
+            "/tmp/shot.png",
+            "--clip",
+            "0,0,200,100",
+            "--selector",
+            "h1",
+            "--session",
+            "s1",
+            "--tab",
+            "t1",
+        ])
+        .expect_err("--clip and --selector must conflict");
+
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn try_parse_from_accepts_global_pretty_flag() {
+        let cli = Cli::try_parse_from([
+            "actionbook",
+            "browser",
+            "list-sessions",
+            "--json",
+            "--pretty",
+        ])
+        .expect("parse --pretty");
+
+        assert!(cli.json);
+        assert!(cli.pretty);
+    }
+
+    #[test]
+    fn try_parse_from_parses_reload_hard_flag() {
+        let cli = Cli::try_parse_from([
+            "actionbook",
+            "browser",
+            "reload",
+            "--hard",
+            "--session",
+            "s1",
+            "--tab",
+            "t1",
+        ])
+        .expect("parse reload --hard");
+
+        match cli.command {
+            Some(Commands::Browser {
+                command: BrowserCommands::Reload(cmd),
+            }) => {
+                assert!(cmd.hard);
+                assert_eq!(cmd.session, "s1");
+                assert_eq!(cmd.tab, "t1");
+            }
+            other => panic!("expected browser reload command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn try_parse_from_parses_inspect_point_selector() {
+        let cli = Cli::try_parse_from([
+            "actionbook",
+            "browser",
+            "inspect-point",
+            "--selector",
+            "#submit",
+            "--session",
+            "s1",
+            "--tab",
+            "t1",
+        ])
+        .expect("parse inspect-point --selector");
+
+        match cli.command {
+            Some(Commands::Browser {
+                command: BrowserCommands::InspectPoint(cmd),
+            }) => {
+                assert_eq!(cmd.selector.as_deref(), Some("#submit"));
+                assert_eq!(cmd.coordinates, None);
+            }
+            other => panic!("expected browser inspect-point command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn try_parse_from_rejects_inspect_point_selector_with_coordinates() {
+        let result = Cli::try_parse_from([
+            "actionbook",
+            "browser",
+            "inspect-point",
+            "10,20",
+            "--selector",
+            "#submit",
+            "--session",
+            "s1",
+            "--tab",
+            "t1",
+        ]);
+        assert!(result.is_err(), "coordinates and --selector must conflict");
+
+        let result = Cli::try_parse_from([
+            "actionbook",
+            "browser",
+            "inspect-point",
+            "--session",
+            "s1",
+            "--tab",
+            "t1",
+        ]);
+        assert!(
+            result.is_err(),
+            "one of coordinates or --selector is required"
+        );
+    }
+
     #[test]
     fn try_parse_from_parses_attr_selector_and_name() {
         let cli = Cli::try_parse_from([
@@ -1324,6 +1686,115 @@ mod tests {
         }
     }
 
+    #[test]
+    fn try_parse_from_parses_exists_and_visible() {
+        for name in ["exists", "visible"] {
+            let cli = Cli::try_parse_from([
+                "actionbook",
+                "browser",
+                name,
+                "#banner",
+                "--session",
+                "s1",
+                "--tab",
+                "t1",
+            ])
+            .unwrap_or_else(|e| panic!("parse {name}: {e}"));
+
+            match cli.command {
+                Some(Commands::Browser {
+                    command: BrowserCommands::Exists(cmd),
+                }) if name == "exists" => {
+                    assert_eq!(cmd.selector, "#banner");
+                    assert_eq!(cmd.session, "s1");
+                    assert_eq!(cmd.tab, "t1");
+                }
+                Some(Commands::Browser {
+                    command: BrowserCommands::Visible(cmd),
+                }) if name == "visible" => {
+                    assert_eq!(cmd.selector, "#banner");
+                    assert_eq!(cmd.session, "s1");
+                    assert_eq!(cmd.tab, "t1");
+                }
+                other => panic!("expected browser {name} command, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn try_parse_from_parses_snapshot_roles() {
+        let cli = Cli::try_parse_from([
+            "actionbook",
+            "browser",
+            "snapshot",
+            "--role",
+            "heading,link",
+            "--role",
+            "button",
+            "--session",
+            "s1",
+            "--tab",
+            "t1",
+        ])
+        .expect("parse snapshot --role");
+
+        match cli.command {
+            Some(Commands::Browser {
+                command: BrowserCommands::Snapshot(cmd),
+            }) => assert_eq!(cmd.role, ["heading", "link", "button"]),
+            other => panic!("expected browser snapshot command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn try_parse_from_parses_profiles_delete() {
+        let cli = Cli::try_parse_from([
+            "actionbook",
+            "browser",
+            "profiles",
+            "delete",
+            "work",
+            "--yes",
+        ])
+        .expect("parse profiles delete");
+
+        match cli.command {
+            Some(Commands::Browser {
+                command:
+                    BrowserCommands::Profiles {
+                        command: ProfilesCommands::Delete(cmd),
+                    },
+            }) => {
+                assert_eq!(cmd.name, "work");
+                assert!(cmd.yes);
+            }
+            other => panic!("expected browser profiles delete command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn try_parse_from_parses_viewport_set() {
+        let cli = Cli::try_parse_from([
+            "actionbook",
+            "browser",
+            "viewport",
+            "--set",
+            "1280x800",
+            "--session",
+            "s1",
+            "--tab",
+            "t1",
+        ])
+        .expect("parse viewport --set");
+
+        match cli.command {
+            Some(Commands::Browser {
+                command: BrowserCommands::Viewport(cmd),
+            }) => assert_eq!(cmd.set.as_deref(), Some("1280x800")),
+            other => panic!("expected browser viewport command, got {other:?}"),
+        }
+    }
+
     #[test]
     fn try_parse_from_parses_styles_names_after_options() {
         let cli = Cli::try_parse_from([
@@ -1383,6 +1854,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn try_parse_from_accepts_browser_start_extension_flags() {
+        let cli = Cli::try_parse_from([
+            "actionbook",
+            "browser",
+            "start",
+            "--mode",
+            "extension",
+            "--open-url",
+            "https://example.com",
+            "--command-timeout",
+            "15000",
+            "--extension-id",
+            "d_work",
+            "--connect-retries",
+            "5",
+            "--heartbeat-interval",
+            "5000",
+        ])
+        .expect("browser start --command-timeout should parse");
+
+        match cli.command {
+            Some(Commands::Browser {
+                command: BrowserCommands::Start(cmd),
+            }) => {
+                assert_eq!(cmd.command_timeout, Some(15_000));
+                assert_eq!(cmd.extension_id.as_deref(), Some("d_work"));
+                assert_eq!(cmd.connect_retries, Some(5));
+                assert_eq!(cmd.heartbeat_interval, Some(5_000));
+            }
+            other => panic!("expected browser start command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn try_parse_from_accepts_browser_start_auto_dismiss_dialogs() {
+        let parse = |args: &[&str]| match Cli::try_parse_from(args)
+            .expect("browser start should parse")
+            .command
+        {
+            Some(Commands::Browser {
+                command: BrowserCommands::Start(cmd),
+            }) => cmd.auto_dismiss_dialogs,
+            other => panic!("expected browser start command, got {other:?}"),
+        };
+        assert!(parse(&["actionbook", "browser", "start"]));
+        assert!(!parse(&[
+            "actionbook",
+            "browser",
+            "start",
+            "--auto-dismiss-dialogs",
+            "false",
+        ]));
+    }
+
     #[test]
     fn try_parse_from_rejects_session_and_set_session_id_together() {
         let result = Cli::try_parse_from([
@@ -3,6 +3,7 @@ use serde::Deserialize;
 use crate::api_response;
 use crate::config;
 use crate::formatter;
+use crate::output;
 
 #[derive(Deserialize, Clone)]
 struct Authentication {
@@ -170,7 +171,7 @@ pub async fn run(
     let data = api_response::unwrap_data(serde_json::from_str(&body_text)?);
 
     if json {
-        println!("{}", serde_json::to_string_pretty(&data)?);
+        println!("{}", output::to_json_string(&data)?);
         return Ok(());
     }
 
@@ -5,6 +5,7 @@ use serde::Deserialize;
 use crate::api_response;
 use crate::config;
 use crate::formatter;
+use crate::output;
 
 pub async fn run(keyword: &str, json: bool) -> Result<(), Box<dyn std::error::Error>> {
     let client = reqwest::Client::builder()
@@ -35,7 +36,7 @@ pub async fn run(keyword: &str, json: bool) -> Result<(), Box<dyn std::error::Er
     let data = api_response::unwrap_data(serde_json::from_str(&body_text)?);
 
     if json {
-        println!("{}", serde_json::to_string_pretty(&data)?);
+        println!("{}", output::to_json_string(&data)?);
         return Ok(());
     }
 
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -18,6 +19,9 @@ pub(crate) struct ConfigFile {
     pub(crate) version: Option<u32>,
     pub(crate) api: ApiConfig,
     pub(crate) browser: BrowserConfig,
+    /// Per-profile settings, keyed by profile name (`[profiles.<name>]`).
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) profiles: BTreeMap<String, ProfileConfig>,
 }
 
 impl Default for ConfigFile {
@@ -26,6 +30,7 @@ impl Default for ConfigFile {
             version: Some(CURRENT_CONFIG_VERSION),
             api: ApiConfig::default(),
             browser: BrowserConfig::default(),
+            profiles: BTreeMap::new(),
         }
     }
 }
@@ -68,6 +73,43 @@ fn default_profile_name() -> String {
     DEFAULT_PROFILE.to_string()
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+pub(crate) struct ProfileConfig {
+    /// Viewport (width, height) that local sessions for this profile open at.
+    /// Unset keeps Chrome's default window size.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) viewport: Option<(u32, u32)>,
+}
+
+/// Saved viewport for `profile`, if any. An unreadable config counts as unset.
+pub(crate) fn profile_viewport(profile: &str) -> Option<(u32, u32)> {
+    load_config().ok()?.profiles.get(profile)?.viewport
+}
+
+/// Persist `viewport` for `profile`, keeping the rest of the config intact.
+pub(crate) fn save_profile_viewport(
+    profile: &str,
+    viewport: (u32, u32),
+) -> Result<PathBuf, CliError> {
+    let mut config = load_config()?;
+    config
+        .profiles
+        .entry(profile.to_string())
+        .or_default()
+        .viewport = Some(viewport);
+    save_config(&config)
+}
+
+/// Drop any saved settings for `profile`. A no-op when none are stored.
+pub(crate) fn remove_profile_config(profile: &str) -> Result<(), CliError> {
+    let mut config = load_config()?;
+    if config.profiles.remove(profile).is_some() {
+        save_config(&config)?;
+    }
+    Ok(())
+}
+
 /// Return the base URL for the Actionbook API.
 /// Precedence: ACTIONBOOK_API_URL env var > config file api.base_url > production endpoint.
 ///
@@ -473,7 +515,12 @@ mod tests {
             session: None,
             set_session_id: None,
             stealth: true,
+            auto_dismiss_dialogs: true,
             max_tracked_requests: 500,
+            command_timeout: None,
+            connect_retries: None,
+            heartbeat_interval: None,
+            extension_id: None,
             provider_env: Default::default(),
         }
     }
@@ -728,6 +775,22 @@ base_url = "  https://config-api.example.com  "
         assert_eq!(api_base_from_config(&config), "https://env-api.example.com");
     }
 
+    #[test]
+    fn profile_viewport_round_trips_through_config_file() {
+        let _lock = test_lock();
+        let (_tmp, _guard) = make_home();
+
+        assert_eq!(profile_viewport("work"), None);
+        save_profile_viewport("work", (1280, 800)).expect("save");
+        save_profile_viewport("work", (1440, 900)).expect("overwrite");
+
+        assert_eq!(profile_viewport("work"), Some((1440, 900)));
+        assert_eq!(profile_viewport(DEFAULT_PROFILE), None);
+        let text = fs::read_to_string(config_path()).expect("read config");
+        assert!(text.contains("[profiles.work]"), "{text}");
+        assert_eq!(load_config().expect("load").browser.mode, Mode::Local);
+    }
+
     /// Verify that `actionbook_home()` falls back to USERPROFILE when HOME is not set.
     /// This is the Windows home directory convention.
     /// Before the Windows fix this test fails (returns /tmp/.actionbook instead).
@@ -4,12 +4,21 @@
 //! port. Two types of clients connect:
 //!
 //! 1. **Extension** — Chrome extension connects with a hello handshake. Origin
-//!    is validated against known extension IDs. One extension connection at a time.
+//!    is validated against known extension IDs. Several extensions (e.g. one
+//!    per Chrome profile) may be connected at once; each is keyed by the
+//!    `deviceId` from its hello, and a second connection with the same id is
+//!    rejected.
+//!    The bridge pings it periodically and evicts it once it stops answering,
+//!    so a half-open socket doesn't keep swallowing commands.
 //!
 //! 2. **CDP client** (daemon CdpSession) — connects for transparent CDP relay.
 //!    First message is inspected: if it contains `"type":"hello"` it's an
 //!    extension; otherwise it's treated as a CDP client and all messages are
-//!    relayed bidirectionally to the extension.
+//!    relayed bidirectionally to the extension named by the `extension_id`
+//!    query parameter. Without it the client is paired with the only
+//!    connected extension, and rejected when several are connected. One CDP
+//!    client per extension. Requests sent while no extension is connected get
+//!    an immediate "extension not connected" error.
 //!
 //! The bridge is spawned from `run_daemon()`. Binding the fixed port is
 //! attempted with bounded exponential backoff so transient contention
@@ -17,6 +26,7 @@
 //! use of 19222) does not permanently break extension mode. If every attempt
 //! fails the daemon still starts — only extension mode is unavailable.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -24,7 +34,7 @@ use futures_util::{SinkExt, StreamExt};
 use serde_json::json;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::{TcpListener, TcpStream};
-use tokio::sync::{Mutex, mpsc};
+use tokio::sync::{Mutex, mpsc, watch};
 use tokio_tungstenite::tungstenite::Message;
 use tokio_tungstenite::tungstenite::http::StatusCode;
 use tracing::{error, info, warn};
@@ -40,6 +50,25 @@ pub const BRIDGE_PORT: u16 = 19222;
 /// exit, rapid daemon restart races, and brief third-party port use.
 const BIND_RETRY_DELAYS_MS: &[u64] = &[100, 500, 1_000, 2_000, 5_000];
 
+/// Delays (in ms) between attempts when the daemon's CdpSession connects to
+/// the bridge. Kept short so a bridge that is genuinely down still fails fast;
+/// it only needs to ride out a listener that is mid-restart.
+const CONNECT_RETRY_DELAYS_MS: &[u64] = &[100, 250, 500];
+
+/// Default interval between WebSocket pings sent to the connected extension.
+const HEARTBEAT_INTERVAL_MS: u64 = 10_000;
+
+/// Accepted range for `browser start --heartbeat-interval` (ms).
+pub const HEARTBEAT_INTERVAL_RANGE_MS: std::ops::RangeInclusive<u64> = 1_000..=300_000;
+
+/// The extension is evicted after this many heartbeat intervals without any
+/// inbound frame (pong or otherwise) — i.e. its socket is half-open.
+const HEARTBEAT_MISSED_LIMIT: u32 = 3;
+
+/// Client id for extensions whose hello carries no `deviceId` (local-mode
+/// builds before multi-extension support). Two of them still collide.
+pub const DEFAULT_EXTENSION_ID: &str = "default";
+
 /// Protocol version for the hello handshake.
 ///
 /// Bumped to `0.4.0` when `Extension.listTabs` was narrowed from "every
@@ -82,41 +111,78 @@ pub enum BridgeListenerStatus {
     Failed,
 }
 
+/// A registered extension connection.
+struct ExtensionConn {
+    /// Send commands TO this extension's WebSocket.
+    tx: mpsc::UnboundedSender<String>,
+    /// Connection id, so a stale handler never unregisters its replacement.
+    connection_id: u64,
+}
+
 /// Bridge state shared across connections.
 pub struct BridgeState {
-    /// Send commands TO the extension WebSocket.
-    extension_tx: Option<mpsc::UnboundedSender<String>>,
-    /// Send messages TO the CDP client (daemon CdpSession) WebSocket.
-    cdp_tx: Option<mpsc::UnboundedSender<String>>,
+    /// Connected extensions keyed by client id.
+    extensions: BTreeMap<String, ExtensionConn>,
+    /// Send messages TO each CDP client (daemon CdpSession) WebSocket, keyed
+    /// by the extension id the client is bound to. Kept apart from
+    /// `extensions` so a client survives its extension reconnecting.
+    cdp_clients: BTreeMap<String, mpsc::UnboundedSender<String>>,
     /// Monotonically increasing connection id to distinguish extension connections.
     connection_id: u64,
     /// Last activity timestamp.
     last_activity: Instant,
     /// Listener bind state (updated by the background bind task).
     listener_status: BridgeListenerStatus,
+    /// Interval between heartbeat pings to the extension.
+    /// `HEARTBEAT_INTERVAL_MS` unless `browser start --heartbeat-interval`
+    /// set it; connected extensions pick up changes immediately.
+    heartbeat_interval: watch::Sender<std::time::Duration>,
 }
 
 impl BridgeState {
     fn new() -> Self {
         Self {
-            extension_tx: None,
-            cdp_tx: None,
+            extensions: BTreeMap::new(),
+            cdp_clients: BTreeMap::new(),
             connection_id: 0,
             last_activity: Instant::now(),
             listener_status: BridgeListenerStatus::Binding,
+            heartbeat_interval: watch::channel(std::time::Duration::from_millis(
+                HEARTBEAT_INTERVAL_MS,
+            ))
+            .0,
         }
     }
 
+    /// Change the heartbeat interval for current and future extension
+    /// connections. Callers validate against `HEARTBEAT_INTERVAL_RANGE_MS`.
+    pub fn set_heartbeat_interval(&self, interval: std::time::Duration) {
+        self.heartbeat_interval.send_replace(interval);
+    }
+
     fn touch(&mut self) {
         self.last_activity = Instant::now();
     }
 
-    /// Whether an extension is currently connected (channel is open).
+    /// Whether any extension is currently connected (channel is open).
     pub fn is_extension_connected(&self) -> bool {
-        self.extension_tx
-            .as_ref()
-            .map(|tx| !tx.is_closed())
-            .unwrap_or(false)
+        self.extensions.values().any(|c| !c.tx.is_closed())
+    }
+
+    /// Ids of the currently connected extensions, sorted.
+    pub fn extension_ids(&self) -> Vec<String> {
+        self.extensions
+            .iter()
+            .filter(|(_, c)| !c.tx.is_closed())
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    fn extension_tx(&self, id: &str) -> Option<&mpsc::UnboundedSender<String>> {
+        self.extensions
+            .get(id)
+            .map(|c| &c.tx)
+            .filter(|tx| !tx.is_closed())
     }
 
     /// Current listener status.
@@ -372,6 +438,20 @@ async fn bind_with_retry(addr: &str, delays_ms: &[u64]) -> std::io::Result<TcpLi
     Err(last_err)
 }
 
+/// Retry schedule for connecting a CdpSession to the bridge.
+///
+/// Defaults to `CONNECT_RETRY_DELAYS_MS`; `browser start --connect-retries`
+/// overrides the retry count (extra retries reuse the last delay).
+pub fn connect_retry_delays(retries: Option<usize>) -> Vec<u64> {
+    let Some(n) = retries else {
+        return CONNECT_RETRY_DELAYS_MS.to_vec();
+    };
+    let last = *CONNECT_RETRY_DELAYS_MS.last().unwrap_or(&0);
+    (0..n)
+        .map(|i| CONNECT_RETRY_DELAYS_MS.get(i).copied().unwrap_or(last))
+        .collect()
+}
+
 // ─── Accept Loop ────────────────────────────────────────────────────────
 
 async fn accept_loop(listener: TcpListener, state: SharedBridgeState) {
@@ -407,10 +487,14 @@ async fn handle_connection(mut stream: TcpStream, state: SharedBridgeState) {
         }
     }
 
-    // Capture origin during WS upgrade for extension ID validation.
+    // Capture origin during WS upgrade for extension ID validation, and the
+    // `extension_id` query a CDP client uses to pick its extension.
     let captured_origin: Arc<std::sync::Mutex<Option<String>>> =
         Arc::new(std::sync::Mutex::new(None));
     let origin_capture = Arc::clone(&captured_origin);
+    let captured_target: Arc<std::sync::Mutex<Option<String>>> =
+        Arc::new(std::sync::Mutex::new(None));
+    let target_capture = Arc::clone(&captured_target);
 
     let ws = match tokio_tungstenite::accept_hdr_async(
         stream,
@@ -436,6 +520,7 @@ async fn handle_connection(mut stream: TcpStream, state: SharedBridgeState) {
             }
 
             *origin_capture.lock().unwrap() = origin;
+            *target_capture.lock().unwrap() = req.uri().query().and_then(extension_id_from_query);
             Ok(resp)
         },
     )
@@ -446,6 +531,7 @@ async fn handle_connection(mut stream: TcpStream, state: SharedBridgeState) {
     };
 
     let connection_origin = captured_origin.lock().unwrap().take();
+    let requested_extension = captured_target.lock().unwrap().take();
     let (write, mut read) = ws.split();
 
     // Read first message to determine client role.
@@ -466,12 +552,20 @@ async fn handle_connection(mut stream: TcpStream, state: SharedBridgeState) {
         handle_extension(write, read, parsed, connection_origin, state).await;
     } else {
         // Not a hello → assume CDP client (daemon CdpSession).
-        handle_cdp_client(write, read, first_msg, state).await;
+        handle_cdp_client(write, read, first_msg, requested_extension, state).await;
     }
 }
 
 // ─── Extension Handler ──────────────────────────────────────────────────
 
+/// Heartbeat ticker whose immediate first tick is already consumed.
+async fn heartbeat_ticker(period: std::time::Duration) -> tokio::time::Interval {
+    let mut ticker = tokio::time::interval(period);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    ticker.tick().await;
+    ticker
+}
+
 async fn handle_extension(
     mut write: futures_util::stream::SplitSink<
         tokio_tungstenite::WebSocketStream<TcpStream>,
@@ -517,10 +611,17 @@ async fn handle_extension(
         return;
     }
 
-    // Reject if another extension is already connected.
+    let extension_id = hello
+        .get("deviceId")
+        .and_then(|v| v.as_str())
+        .filter(|id| !id.is_empty())
+        .unwrap_or(DEFAULT_EXTENSION_ID)
+        .to_string();
+
+    // Reject if an extension with the same id is already connected.
     {
         let s = state.lock().await;
-        if s.is_extension_connected() {
+        if s.extension_tx(&extension_id).is_some() {
             drop(s);
             let err = json!({
                 "type": "replaced",
@@ -541,18 +642,26 @@ async fn handle_extension(
         return;
     }
 
-    info!("bridge: extension connected");
+    info!("bridge: extension {extension_id} connected");
 
     // Create channel for sending commands TO this extension WS.
     let (ext_tx, mut ext_rx) = mpsc::unbounded_channel::<String>();
 
-    let my_conn_id = {
+    let (my_conn_id, mut heartbeat_rx) = {
         let mut s = state.lock().await;
         s.connection_id += 1;
-        s.extension_tx = Some(ext_tx);
+        let connection_id = s.connection_id;
+        s.extensions.insert(
+            extension_id.clone(),
+            ExtensionConn {
+                tx: ext_tx,
+                connection_id,
+            },
+        );
         s.touch();
-        s.connection_id
+        (s.connection_id, s.heartbeat_interval.subscribe())
     };
+    let mut heartbeat = *heartbeat_rx.borrow_and_update();
 
     // Writer task: channel → extension WS.
     let write = Arc::new(Mutex::new(write));
@@ -566,33 +675,69 @@ async fn handle_extension(
         }
     });
 
+    // Heartbeat: ping every `heartbeat`; Chrome answers pings automatically,
+    // so a socket that stays silent for HEARTBEAT_MISSED_LIMIT intervals is
+    // half-open and gets evicted.
+    let mut ticker = heartbeat_ticker(heartbeat).await;
+    let mut last_seen = Instant::now();
+
     // Reader: extension WS → forward to CDP client (if connected).
-    while let Some(frame) = read.next().await {
-        match frame {
-            Ok(Message::Text(text)) => {
-                let text_str = text.to_string();
-                let mut s = state.lock().await;
-                s.touch();
-                if let Some(ref cdp_tx) = s.cdp_tx
-                    && cdp_tx.send(text_str).is_err()
-                {
-                    warn!("bridge: failed to forward extension message to CDP client");
+    loop {
+        tokio::select! {
+            frame = read.next() => {
+                let Some(frame) = frame else { break };
+                last_seen = Instant::now();
+                match frame {
+                    Ok(Message::Text(text)) => {
+                        let text_str = text.to_string();
+                        let mut s = state.lock().await;
+                        s.touch();
+                        if let Some(cdp_tx) = s.cdp_clients.get(&extension_id)
+                            && cdp_tx.send(text_str).is_err()
+                        {
+                            warn!("bridge: failed to forward extension message to CDP client");
+                        }
+                        // If no CDP client, message is dropped (events before session start).
+                    }
+                    Ok(Message::Close(_)) => break,
+                    Err(_) => break,
+                    _ => {}
+                }
+            }
+            Ok(()) = heartbeat_rx.changed() => {
+                heartbeat = *heartbeat_rx.borrow_and_update();
+                ticker = heartbeat_ticker(heartbeat).await;
+            }
+            _ = ticker.tick() => {
+                if last_seen.elapsed() >= heartbeat * HEARTBEAT_MISSED_LIMIT {
+                    warn!(
+                        "bridge: no frames from extension for {:?} — evicting half-open connection",
+                        last_seen.elapsed()
+                    );
+                    break;
+                }
+                // Bounded so a stuck writer can't stall eviction checks.
+                let ping = tokio::time::timeout(heartbeat, async {
+                    write.lock().await.send(Message::Ping(Default::default())).await
+                })
+                .await;
+                if let Ok(Err(_)) = ping {
+                    break;
                 }
-                // If no CDP client, message is dropped (events before session start).
             }
-            Ok(Message::Close(_)) => break,
-            Err(_) => break,
-            _ => {}
         }
     }
 
-    info!("bridge: extension disconnected");
+    info!("bridge: extension {extension_id} disconnected");
 
-    // Cleanup: only clear if we own the current connection.
+    // Cleanup: only unregister if we own the current connection for this id.
     {
         let mut s = state.lock().await;
-        if s.connection_id == my_conn_id {
-            s.extension_tx = None;
+        if s.extensions
+            .get(&extension_id)
+            .is_some_and(|c| c.connection_id == my_conn_id)
+        {
+            s.extensions.remove(&extension_id);
         }
     }
 
@@ -605,38 +750,53 @@ async fn handle_cdp_client(
     write: futures_util::stream::SplitSink<tokio_tungstenite::WebSocketStream<TcpStream>, Message>,
     mut read: futures_util::stream::SplitStream<tokio_tungstenite::WebSocketStream<TcpStream>>,
     first_message: String,
+    requested_extension: Option<String>,
     state: SharedBridgeState,
 ) {
-    // Reject if another CDP client is already connected. The bridge is a 1:1
-    // relay — allowing a second client would silently steal extension responses
-    // from the first session, causing it to stall/timeout.
-    {
-        let s = state.lock().await;
-        if s.cdp_tx.as_ref().is_some_and(|tx| !tx.is_closed()) {
-            warn!("bridge: rejected CDP client — another session is already connected");
-            return;
-        }
-    }
-
     // Create channel for sending messages TO this CDP client WS.
     let (cdp_tx, mut cdp_rx) = mpsc::unbounded_channel::<String>();
 
-    {
-        let mut s = state.lock().await;
-        s.cdp_tx = Some(cdp_tx);
-        s.touch();
-    }
-
-    // Forward the first CDP message (already read) to extension.
-    {
+    // Bind to the requested extension, else the only connected one (or the
+    // default id when none is connected yet). A client that names no extension
+    // while several are connected is rejected rather than guessed at. Also
+    // reject if another CDP client is already bound to it: each binding is a
+    // 1:1 relay — a second client would silently steal extension responses
+    // from the first session, causing it to stall/timeout.
+    let extension_id = {
         let mut s = state.lock().await;
-        s.touch();
-        if let Some(ref ext_tx) = s.extension_tx
-            && ext_tx.send(first_message).is_err()
+        let extension_id = match requested_extension {
+            Some(id) => id,
+            None => {
+                let ids = s.extension_ids();
+                match ids.as_slice() {
+                    [] => DEFAULT_EXTENSION_ID.to_string(),
+                    [only] => only.clone(),
+                    _ => {
+                        warn!(
+                            "bridge: rejected CDP client without extension_id — {} extensions are connected ({})",
+                            ids.len(),
+                            ids.join(", ")
+                        );
+                        return;
+                    }
+                }
+            }
+        };
+        if s.cdp_clients
+            .get(&extension_id)
+            .is_some_and(|tx| !tx.is_closed())
         {
-            warn!("bridge: failed to forward first CDP message to extension");
+            warn!(
+                "bridge: rejected CDP client — another session is already connected to extension {extension_id}"
+            );
+            return;
         }
-    }
+        s.cdp_clients.insert(extension_id.clone(), cdp_tx);
+        s.touch();
+        // Forward the first CDP message (already read) to extension.
+        forward_to_extension(&s, &extension_id, first_message);
+        extension_id
+    };
 
     // Writer task: channel → CDP client WS.
     let write = Arc::new(Mutex::new(write));
@@ -654,14 +814,9 @@ async fn handle_cdp_client(
     while let Some(frame) = read.next().await {
         match frame {
             Ok(Message::Text(text)) => {
-                let text_str = text.to_string();
                 let mut s = state.lock().await;
                 s.touch();
-                if let Some(ref ext_tx) = s.extension_tx
-                    && ext_tx.send(text_str).is_err()
-                {
-                    warn!("bridge: failed to forward CDP message to extension");
-                }
+                forward_to_extension(&s, &extension_id, text.to_string());
             }
             Ok(Message::Close(_)) => break,
             Err(_) => break,
@@ -672,12 +827,60 @@ async fn handle_cdp_client(
     // Cleanup CDP client channel.
     {
         let mut s = state.lock().await;
-        s.cdp_tx = None;
+        s.cdp_clients.remove(&extension_id);
     }
 
     write_handle.abort();
 }
 
+/// Relay a CDP client message to the extension. With no live extension
+/// (never connected, disconnected, or evicted by the heartbeat), requests get
+/// an immediate error reply so the caller fails fast instead of timing out.
+fn forward_to_extension(s: &BridgeState, extension_id: &str, message: String) {
+    if let Some(ext_tx) = s.extension_tx(extension_id) {
+        if ext_tx.send(message).is_err() {
+            warn!("bridge: failed to forward CDP message to extension {extension_id}");
+        }
+        return;
+    }
+    if let Some(reply) = not_connected_reply(&message)
+        && let Some(cdp_tx) = s.cdp_clients.get(extension_id)
+    {
+        let _ = cdp_tx.send(reply);
+    }
+}
+
+/// Bridge WS URL that binds a CDP client to `extension_id`.
+pub fn bridge_ws_url(port: u16, extension_id: &str) -> String {
+    format!(
+        "ws://127.0.0.1:{port}/?extension_id={}",
+        urlencoding::encode(extension_id)
+    )
+}
+
+fn extension_id_from_query(query: &str) -> Option<String> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(k, _)| *k == "extension_id")
+        .and_then(|(_, v)| urlencoding::decode(v).ok())
+        .map(|v| v.into_owned())
+        .filter(|v| !v.is_empty())
+}
+
+/// Error response for a CDP request that cannot reach the extension.
+fn not_connected_reply(message: &str) -> Option<String> {
+    let parsed: serde_json::Value = serde_json::from_str(message).ok()?;
+    let id = parsed.get("id")?;
+    Some(
+        json!({
+            "id": id,
+            "error": { "code": -32000, "message": "extension not connected" },
+        })
+        .to_string(),
+    )
+}
+
 // ─── Helpers ────────────────────────────────────────────────────────────
 
 fn is_health_check_request(buf: &[u8]) -> bool {
@@ -856,6 +1059,14 @@ mod tests {
         assert_eq!(err.kind(), std::io::ErrorKind::AddrInUse);
     }
 
+    #[test]
+    fn connect_retry_delays_default_and_override() {
+        assert_eq!(connect_retry_delays(None), CONNECT_RETRY_DELAYS_MS);
+        assert!(connect_retry_delays(Some(0)).is_empty());
+        assert_eq!(connect_retry_delays(Some(1)), vec![100]);
+        assert_eq!(connect_retry_delays(Some(5)), vec![100, 250, 500, 500, 500]);
+    }
+
     // ─── ensure_bridge contract (Phase 3 lazy + recovery) ───────────────
 
     use crate::daemon::registry::{SharedRegistry, new_shared_registry};
@@ -1053,4 +1264,274 @@ mod tests {
         let ack: serde_json::Value = serde_json::from_str(&text).unwrap();
         assert_eq!(ack["type"], "hello_ack");
     }
+
+    #[test]
+    fn not_connected_reply_echoes_request_id() {
+        let reply = not_connected_reply(r#"{"id":7,"method":"Page.navigate"}"#).unwrap();
+        let v: serde_json::Value = serde_json::from_str(&reply).unwrap();
+        assert_eq!(v["id"], 7);
+        assert_eq!(v["error"]["message"], "extension not connected");
+        assert!(not_connected_reply(r#"{"method":"Event"}"#).is_none());
+        assert!(not_connected_reply("garbage").is_none());
+    }
+
+    /// Bridge accept loop over `state` on an ephemeral port.
+    async fn spawn_bridge(state: SharedBridgeState) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(accept_loop(listener, state));
+        format!("ws://127.0.0.1:{}", addr.port())
+    }
+
+    #[tokio::test]
+    async fn silent_extension_is_evicted_and_cdp_requests_fail_fast() {
+        let state = new_bridge_state();
+        state
+            .lock()
+            .await
+            .set_heartbeat_interval(std::time::Duration::from_millis(50));
+        let url = spawn_bridge(state.clone()).await;
+
+        let mut request = url.as_str().into_client_request().unwrap();
+        request.headers_mut().insert(
+            "Origin",
+            HeaderValue::from_static("chrome-extension://bebchpafpemheedhcdabookaifcijmfo"),
+        );
+        let (mut ext, _) = connect_async(request).await.unwrap();
+        ext.send(Message::Text(
+            json!({ "type": "hello", "version": PROTOCOL_VERSION })
+                .to_string()
+                .into(),
+        ))
+        .await
+        .unwrap();
+        let ack = ext.next().await.unwrap().unwrap();
+        assert!(matches!(ack, Message::Text(ref t) if t.contains("hello_ack")));
+        assert!(state.lock().await.is_extension_connected());
+
+        // Stop reading: pings go unanswered, like a half-open socket.
+        let deadline = Instant::now() + std::time::Duration::from_secs(3);
+        while state.lock().await.is_extension_connected() {
+            assert!(
+                Instant::now() < deadline,
+                "silent extension was not evicted"
+            );
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+
+        let (mut cdp, _) = connect_async(url.as_str()).await.unwrap();
+        cdp.send(Message::Text(
+            json!({ "id": 1, "method": "Page.navigate" })
+                .to_string()
+                .into(),
+        ))
+        .await
+        .unwrap();
+        let reply = tokio::time::timeout(std::time::Duration::from_secs(2), cdp.next())
+            .await
+            .expect("reply should be immediate")
+            .unwrap()
+            .unwrap();
+        let v: serde_json::Value = match reply {
+            Message::Text(t) => serde_json::from_str(&t).unwrap(),
+            other => panic!("expected text reply, got {other:?}"),
+        };
+        assert_eq!(v["id"], 1);
+        assert_eq!(v["error"]["message"], "extension not connected");
+        drop(ext);
+    }
+
+    #[tokio::test]
+    async fn heartbeat_interval_change_applies_to_connected_extension() {
+        let state = new_bridge_state();
+        let url = spawn_bridge(state.clone()).await;
+
+        let mut request = url.as_str().into_client_request().unwrap();
+        request.headers_mut().insert(
+            "Origin",
+            HeaderValue::from_static("chrome-extension://bebchpafpemheedhcdabookaifcijmfo"),
+        );
+        let (mut ext, _) = connect_async(request).await.unwrap();
+        ext.send(Message::Text(
+            json!({ "type": "hello", "version": PROTOCOL_VERSION })
+                .to_string()
+                .into(),
+        ))
+        .await
+        .unwrap();
+        let ack = ext.next().await.unwrap().unwrap();
+        assert!(matches!(ack, Message::Text(ref t) if t.contains("hello_ack")));
+
+        // Connected under the 10s default; shortening the interval must
+        // take effect without waiting out the old one.
+        state
+            .lock()
+            .await
+            .set_heartbeat_interval(std::time::Duration::from_millis(50));
+        let deadline = Instant::now() + std::time::Duration::from_secs(3);
+        while state.lock().await.is_extension_connected() {
+            assert!(
+                Instant::now() < deadline,
+                "silent extension was not evicted on the new interval"
+            );
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        drop(ext);
+    }
+
+    #[test]
+    fn bridge_ws_url_round_trips_extension_id() {
+        let url = bridge_ws_url(19222, "d_a b&c");
+        assert_eq!(url, "ws://127.0.0.1:19222/?extension_id=d_a%20b%26c");
+        let query = url.split_once('?').unwrap().1;
+        assert_eq!(extension_id_from_query(query).as_deref(), Some("d_a b&c"));
+        assert!(extension_id_from_query("other=1").is_none());
+        assert!(extension_id_from_query("extension_id=").is_none());
+    }
+
+    /// Connect a fake extension with `device_id` and complete the handshake.
+    async fn connect_extension(
+        url: &str,
+        device_id: &str,
+    ) -> tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<TcpStream>> {
+        let mut request = url.into_client_request().unwrap();
+        request.headers_mut().insert(
+            "Origin",
+            HeaderValue::from_static("chrome-extension://bebchpafpemheedhcdabookaifcijmfo"),
+        );
+        let (mut ws, _) = connect_async(request).await.unwrap();
+        ws.send(Message::Text(
+            json!({ "type": "hello", "version": PROTOCOL_VERSION, "deviceId": device_id })
+                .to_string()
+                .into(),
+        ))
+        .await
+        .unwrap();
+        let ack = ws.next().await.unwrap().unwrap();
+        assert!(matches!(ack, Message::Text(ref t) if t.contains("hello_ack")));
+        ws
+    }
+
+    async fn next_text<S>(ws: &mut S) -> serde_json::Value
+    where
+        S: StreamExt<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
+    {
+        loop {
+            let msg = tokio::time::timeout(std::time::Duration::from_secs(2), ws.next())
+                .await
+                .expect("message within 2s")
+                .unwrap()
+                .unwrap();
+            if let Message::Text(t) = msg {
+                return serde_json::from_str(&t).unwrap();
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn multiple_extensions_route_by_extension_id() {
+        let state = new_bridge_state();
+        let url = spawn_bridge(state.clone()).await;
+        let mut ext_a = connect_extension(&url, "d_a").await;
+        let mut ext_b = connect_extension(&url, "d_b").await;
+        assert_eq!(state.lock().await.extension_ids(), vec!["d_a", "d_b"]);
+
+        let url_a = format!("{url}/?extension_id=d_a");
+        let url_b = format!("{url}/?extension_id=d_b");
+        let (mut cdp_a, _) = connect_async(url_a.as_str()).await.unwrap();
+        let (mut cdp_b, _) = connect_async(url_b.as_str()).await.unwrap();
+
+        cdp_a
+            .send(Message::Text(
+                json!({ "id": 1, "method": "A" }).to_string().into(),
+            ))
+            .await
+            .unwrap();
+        cdp_b
+            .send(Message::Text(
+                json!({ "id": 1, "method": "B" }).to_string().into(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(next_text(&mut ext_a).await["method"], "A");
+        assert_eq!(next_text(&mut ext_b).await["method"], "B");
+
+        // Same request id on both sides — responses go back to their own client.
+        ext_b
+            .send(Message::Text(
+                json!({ "id": 1, "result": "from-b" }).to_string().into(),
+            ))
+            .await
+            .unwrap();
+        ext_a
+            .send(Message::Text(
+                json!({ "id": 1, "result": "from-a" }).to_string().into(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(next_text(&mut cdp_a).await["result"], "from-a");
+        assert_eq!(next_text(&mut cdp_b).await["result"], "from-b");
+
+        // A duplicate device id is turned away; the original stays registered.
+        let mut request = url.as_str().into_client_request().unwrap();
+        request.headers_mut().insert(
+            "Origin",
+            HeaderValue::from_static("chrome-extension://bebchpafpemheedhcdabookaifcijmfo"),
+        );
+        let (mut dup, _) = connect_async(request).await.unwrap();
+        dup.send(Message::Text(
+            json!({ "type": "hello", "version": PROTOCOL_VERSION, "deviceId": "d_a" })
+                .to_string()
+                .into(),
+        ))
+        .await
+        .unwrap();
+        assert_eq!(next_text(&mut dup).await["type"], "replaced");
+        assert_eq!(state.lock().await.extension_ids(), vec!["d_a", "d_b"]);
+    }
+
+    #[tokio::test]
+    async fn cdp_client_without_extension_id_is_rejected_when_ambiguous() {
+        let state = new_bridge_state();
+        let url = spawn_bridge(state.clone()).await;
+        let mut ext_a = connect_extension(&url, "d_a").await;
+        let (mut cdp, _) = connect_async(url.as_str()).await.unwrap();
+        cdp.send(Message::Text(
+            json!({ "id": 1, "method": "A" }).to_string().into(),
+        ))
+        .await
+        .unwrap();
+        // The only connected extension is picked.
+        assert_eq!(next_text(&mut ext_a).await["method"], "A");
+        drop(cdp);
+
+        let _ext_b = connect_extension(&url, "d_b").await;
+        let (mut cdp, _) = connect_async(url.as_str()).await.unwrap();
+        cdp.send(Message::Text(
+            json!({ "id": 2, "method": "B" }).to_string().into(),
+        ))
+        .await
+        .unwrap();
+        let closed = tokio::time::timeout(std::time::Duration::from_secs(2), async {
+            loop {
+                match cdp.next().await {
+                    None | Some(Err(_)) | Some(Ok(Message::Close(_))) => break,
+                    Some(Ok(_)) => {}
+                }
+            }
+        })
+        .await;
+        assert!(
+            closed.is_ok(),
+            "ambiguous CDP client should be disconnected"
+        );
+        assert!(
+            state
+                .lock()
+                .await
+                .cdp_clients
+                .values()
+                .all(|tx| tx.is_closed())
+        );
+    }
 }
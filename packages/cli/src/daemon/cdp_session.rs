@@ -38,6 +38,14 @@ type PendingIframeEnables = Arc<Mutex<Vec<String>>>;
 
 pub const MAX_TRACKED_REQUESTS: usize = 500;
 
+type WsStream =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+/// Default upper bound on waiting for a command response. 60s covers slow
+/// operations (PDF, screenshot, large eval) while still catching genuinely
+/// hung connections. Overridable per session via `set_command_timeout_ms`.
+pub const DEFAULT_COMMAND_TIMEOUT_MS: u64 = 60_000;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TrackedRequest {
     pub request_id: String,
@@ -345,7 +353,8 @@ fn har_extract_headers(headers_val: Option<&Value>) -> Vec<(String, String)> {
 
 type TabHarRecorders = Arc<Mutex<HashMap<String, HarRecorder>>>;
 
-/// CDP routing discriminant for HAR body fetches spawned from reader_loop.
+/// CDP routing discriminant for commands spawned from reader_loop (HAR body
+/// fetches, dialog auto-dismiss).
 #[derive(Clone)]
 enum HarFetchRoute {
     /// Local/cloud flat session — routes via `sessionId` in the CDP frame.
@@ -357,7 +366,8 @@ enum HarFetchRoute {
 /// Send a raw CDP command on an already-open connection and await its response.
 /// This is a minimal analogue of `CdpSession::execute` that can be invoked from
 /// `reader_loop` without requiring a `CdpSession` handle. It is only used for
-/// background HAR body fetches; everything else goes through `execute`.
+/// background HAR body fetches and dialog auto-dismiss; everything else goes
+/// through `execute`.
 ///
 /// Never hold any recorder/event lock across this await.
 async fn send_cdp_raw(
@@ -389,7 +399,7 @@ async fn send_cdp_raw(
     if writer_tx.send(msg.to_string()).await.is_err() {
         pending.lock().await.remove(&id);
         return Err(CliError::SessionClosed(
-            "session was closed while HAR body fetch was pending".to_string(),
+            "session was closed while background command was pending".to_string(),
         ));
     }
 
@@ -703,6 +713,21 @@ pub struct CdpSession {
     /// Per-tab HAR recorders, keyed by CDP flat-session ID.
     /// Present in the map only while `har_start` is active for that tab.
     tab_har_recorders: TabHarRecorders,
+    /// How long `execute`/`execute_on_tab` wait for a response before
+    /// returning `CliError::Timeout` (ms).
+    command_timeout_ms: Arc<AtomicU64>,
+    /// When set, reader_loop dismisses any JavaScript dialog that no
+    /// `browser dialog` subscriber is waiting for, so an unexpected
+    /// alert/confirm can't freeze the tab. `attach`/`register_extension_tab`
+    /// enable the Page domain so the events arrive. Off until
+    /// `set_auto_dismiss_dialogs` — `browser start` turns it on by default.
+    auto_dismiss_dialogs: Arc<std::sync::atomic::AtomicBool>,
+    /// Ring buffer capacity per tab, kept so `reconnect` can respawn
+    /// reader_loop with the same setting.
+    max_tracked_requests: usize,
+    /// Serializes `reconnect` so concurrent commands that all saw the drop
+    /// open one new connection, not one each.
+    reconnect_lock: Arc<Mutex<()>>,
 }
 
 impl CdpSession {
@@ -725,6 +750,70 @@ impl CdpSession {
         headers: &[(String, String)],
         max_tracked_requests: usize,
     ) -> Result<Self, CliError> {
+        let ws = Self::open_ws(ws_url, headers).await?;
+        Ok(Self::from_ws(ws, max_tracked_requests).await)
+    }
+
+    /// Connect with bounded retry. First attempt is immediate; while the WS
+    /// connect itself fails, waits `delays_ms[i]` and tries again, for a total
+    /// of `delays_ms.len() + 1` attempts. Other errors (bad URL/headers) and
+    /// the last connect error are returned as-is.
+    pub async fn connect_with_retry(
+        ws_url: &str,
+        headers: &[(String, String)],
+        max_tracked_requests: usize,
+        delays_ms: &[u64],
+    ) -> Result<Self, CliError> {
+        let ws = Self::open_ws_with_retry(ws_url, headers, delays_ms).await?;
+        Ok(Self::from_ws(ws, max_tracked_requests).await)
+    }
+
+    /// Whether the WebSocket is still up. Turns false once the peer drops the
+    /// connection (reader_loop sees EOF) or after `close()`.
+    pub async fn is_connected(&self) -> bool {
+        self.writer_tx
+            .lock()
+            .await
+            .as_ref()
+            .is_some_and(|tx| !tx.is_closed())
+    }
+
+    /// Replace a dropped connection with a fresh one to `ws_url`, retrying
+    /// per `delays_ms` like [`Self::connect_with_retry`]. Every clone of this
+    /// session shares the new socket; requests and event subscriptions of the
+    /// old one were already failed/cleared when it dropped.
+    ///
+    /// Extension-bridge sessions re-attach their registered tabs, since the
+    /// extension may have lost them while the bridge was away.
+    pub async fn reconnect(&self, ws_url: &str, delays_ms: &[u64]) -> Result<(), CliError> {
+        let _guard = self.reconnect_lock.lock().await;
+        if self.is_connected().await {
+            return Ok(());
+        }
+        let ws = Self::open_ws_with_retry(ws_url, &[], delays_ms).await?;
+        if let Some(handle) = self.reader_handle.lock().await.take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.writer_handle.lock().await.take() {
+            handle.abort();
+        }
+        self.spawn_io(ws).await;
+
+        if self.is_extension_bridge.load(Ordering::Acquire) {
+            let tabs: Vec<String> = self.tab_sessions.lock().await.keys().cloned().collect();
+            for native_id in tabs {
+                let Ok(tab_id) = native_id.parse::<u64>() else {
+                    continue;
+                };
+                self.execute_browser("Extension.attachTab", json!({ "tabId": tab_id }))
+                    .await?;
+                self.enable_extension_tab_domains(tab_id).await;
+            }
+        }
+        Ok(())
+    }
+
+    async fn open_ws(ws_url: &str, headers: &[(String, String)]) -> Result<WsStream, CliError> {
         let mut request = ws_url
             .into_client_request()
             .map_err(|e| CliError::CdpConnectionFailed(format!("invalid WS URL: {e}")))?;
@@ -742,56 +831,100 @@ impl CdpSession {
         let (ws, _) = connect_async(request)
             .await
             .map_err(|e| CliError::CdpConnectionFailed(e.to_string()))?;
+        Ok(ws)
+    }
+
+    async fn open_ws_with_retry(
+        ws_url: &str,
+        headers: &[(String, String)],
+        delays_ms: &[u64],
+    ) -> Result<WsStream, CliError> {
+        let mut last_err = match Self::open_ws(ws_url, headers).await {
+            Err(e @ CliError::CdpConnectionFailed(_)) => e,
+            other => return other,
+        };
+        let total = delays_ms.len() + 1;
+        for (i, &delay_ms) in delays_ms.iter().enumerate() {
+            tracing::info!(
+                "cdp: connect {ws_url} attempt {}/{total} failed ({last_err}) — retrying in {delay_ms}ms",
+                i + 1
+            );
+            tokio::time::sleep(std::time::Duration::from_millis(delay_ms)).await;
+            match Self::open_ws(ws_url, headers).await {
+                Err(e @ CliError::CdpConnectionFailed(_)) => last_err = e,
+                other => return other,
+            }
+        }
+        Err(last_err)
+    }
+
+    async fn from_ws(ws: WsStream, max_tracked_requests: usize) -> Self {
+        let session = CdpSession {
+            writer_tx: Arc::new(Mutex::new(None)),
+            writer_handle: Arc::new(Mutex::new(None)),
+            reader_handle: Arc::new(Mutex::new(None)),
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            next_id: Arc::new(AtomicU64::new(1)),
+            tab_sessions: Arc::new(Mutex::new(HashMap::new())),
+            event_subs: Arc::new(Mutex::new(HashMap::new())),
+            tab_net_pending: Arc::new(Mutex::new(HashMap::new())),
+            iframe_sessions: Arc::new(Mutex::new(HashMap::new())),
+            pending_iframe_enables: Arc::new(Mutex::new(Vec::new())),
+            tab_net_requests: Arc::new(Mutex::new(HashMap::new())),
+            is_extension_bridge: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            tab_har_recorders: Arc::new(Mutex::new(HashMap::new())),
+            command_timeout_ms: Arc::new(AtomicU64::new(DEFAULT_COMMAND_TIMEOUT_MS)),
+            auto_dismiss_dialogs: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            max_tracked_requests,
+            reconnect_lock: Arc::new(Mutex::new(())),
+        };
+        session.spawn_io(ws).await;
+        session
+    }
 
+    /// Spawn writer_loop/reader_loop over `ws` and install the new writer
+    /// channel and task handles.
+    async fn spawn_io(&self, ws: WsStream) {
         let (ws_writer, ws_reader) = ws.split();
-        let pending: PendingRequests = Arc::new(Mutex::new(HashMap::new()));
-        let next_id = Arc::new(AtomicU64::new(1));
         let (writer_tx, writer_rx) = mpsc::channel::<String>(64);
         // Clone for reader_loop — it needs to dispatch async HAR body fetches
         // (Network.getResponseBody) without blocking itself.
         let writer_tx_for_reader = writer_tx.clone();
-        let event_subs: EventSubs = Arc::new(Mutex::new(HashMap::new()));
-        let tab_net_pending: TabNetPending = Arc::new(Mutex::new(HashMap::new()));
-        let iframe_sessions: IframeSessions = Arc::new(Mutex::new(HashMap::new()));
-        let pending_iframe_enables: PendingIframeEnables = Arc::new(Mutex::new(Vec::new()));
-        let tab_sessions: Arc<Mutex<HashMap<String, String>>> =
-            Arc::new(Mutex::new(HashMap::new()));
-        let tab_net_requests: TabNetRequests = Arc::new(Mutex::new(HashMap::new()));
-        let is_extension_bridge = Arc::new(std::sync::atomic::AtomicBool::new(false));
-        let tab_har_recorders: TabHarRecorders = Arc::new(Mutex::new(HashMap::new()));
-
-        let writer_handle = tokio::spawn(Self::writer_loop(ws_writer, writer_rx));
-        let reader_handle = tokio::spawn(Self::reader_loop(
+        // Fired when reader_loop sees the peer drop the connection so
+        // writer_loop stops too. Not fired on abort (`close()`), where
+        // writer_loop must still send its Close frame.
+        let (dropped_tx, dropped_rx) = oneshot::channel::<()>();
+
+        let writer_handle = tokio::spawn(Self::writer_loop(
+            ws_writer,
+            writer_rx,
+            dropped_rx,
+            self.pending.clone(),
+        ));
+        let reader = Self::reader_loop(
             ws_reader,
-            pending.clone(),
-            event_subs.clone(),
-            tab_net_pending.clone(),
-            iframe_sessions.clone(),
-            pending_iframe_enables.clone(),
-            tab_sessions.clone(),
-            tab_net_requests.clone(),
-            max_tracked_requests,
-            is_extension_bridge.clone(),
-            tab_har_recorders.clone(),
+            self.pending.clone(),
+            self.event_subs.clone(),
+            self.tab_net_pending.clone(),
+            self.iframe_sessions.clone(),
+            self.pending_iframe_enables.clone(),
+            self.tab_sessions.clone(),
+            self.tab_net_requests.clone(),
+            self.max_tracked_requests,
+            self.is_extension_bridge.clone(),
+            self.tab_har_recorders.clone(),
             writer_tx_for_reader,
-            next_id.clone(),
-        ));
+            self.next_id.clone(),
+            self.auto_dismiss_dialogs.clone(),
+        );
+        let reader_handle = tokio::spawn(async move {
+            reader.await;
+            let _ = dropped_tx.send(());
+        });
 
-        Ok(CdpSession {
-            writer_tx: Arc::new(Mutex::new(Some(writer_tx))),
-            writer_handle: Arc::new(Mutex::new(Some(writer_handle))),
-            reader_handle: Arc::new(Mutex::new(Some(reader_handle))),
-            pending,
-            next_id,
-            tab_sessions,
-            event_subs,
-            tab_net_pending,
-            iframe_sessions,
-            pending_iframe_enables,
-            tab_net_requests,
-            is_extension_bridge,
-            tab_har_recorders,
-        })
+        *self.writer_tx.lock().await = Some(writer_tx);
+        *self.writer_handle.lock().await = Some(writer_handle);
+        *self.reader_handle.lock().await = Some(reader_handle);
     }
 
     /// Attach to a CDP target (tab) using flat session mode.
@@ -880,6 +1013,14 @@ impl CdpSession {
             )
             .await;
 
+        // Page events carry Page.javascriptDialogOpening for auto-dismiss.
+        // Best-effort, like setAutoAttach above.
+        if self.auto_dismiss_dialogs() {
+            let _ = self
+                .execute("Page.enable", json!({}), Some(&session_id))
+                .await;
+        }
+
         // Apply stealth when user_agent is provided (stealth mode enabled).
         self.apply_stealth(&session_id, user_agent).await;
 
@@ -964,11 +1105,6 @@ impl CdpSession {
             return;
         }
 
-        // Best-effort: enable the Network domain in the extension-attached tab.
-        // Failure here shouldn't block tab registration — the tab may not have
-        // debugger attached yet (discovered via listTabs but never attached),
-        // or the extension may have been reloaded. Commands that strictly need
-        // Network events will surface the gap themselves.
         let tab_id: u64 = match native_id.parse() {
             Ok(v) => v,
             Err(_) => {
@@ -978,13 +1114,28 @@ impl CdpSession {
                 return;
             }
         };
+        self.enable_extension_tab_domains(tab_id).await;
+    }
+
+    /// Best-effort: enable the Network domain (and Page, for dialog
+    /// auto-dismiss) in an extension-attached tab. Failure here shouldn't
+    /// block tab registration — the tab may not have debugger attached yet
+    /// (discovered via listTabs but never attached), or the extension may
+    /// have been reloaded. Commands that strictly need Network events will
+    /// surface the gap themselves.
+    async fn enable_extension_tab_domains(&self, tab_id: u64) {
         if let Err(e) = self
             .execute_extension_tab(tab_id, "Network.enable", json!({}))
             .await
         {
-            tracing::warn!(
-                "register_extension_tab: Network.enable failed for tab {native_id}: {e}"
-            );
+            tracing::warn!("register_extension_tab: Network.enable failed for tab {tab_id}: {e}");
+        }
+        if self.auto_dismiss_dialogs()
+            && let Err(e) = self
+                .execute_extension_tab(tab_id, "Page.enable", json!({}))
+                .await
+        {
+            tracing::warn!("register_extension_tab: Page.enable failed for tab {tab_id}: {e}");
         }
     }
 
@@ -1154,7 +1305,7 @@ impl CdpSession {
             ));
         }
 
-        let resp = tokio::time::timeout(std::time::Duration::from_secs(60), rx)
+        let resp = tokio::time::timeout(self.command_timeout(), rx)
             .await
             .map_err(|_| {
                 let pending = self.pending.clone();
@@ -1186,6 +1337,27 @@ impl CdpSession {
         Ok(resp)
     }
 
+    /// Override how long commands wait for a response on this session.
+    pub fn set_command_timeout_ms(&self, ms: u64) {
+        self.command_timeout_ms.store(ms, Ordering::Relaxed);
+    }
+
+    /// Turn dialog auto-dismiss on or off. Call before attaching tabs: the
+    /// Page domain is only enabled for tabs attached while it is on.
+    pub fn set_auto_dismiss_dialogs(&self, on: bool) {
+        self.auto_dismiss_dialogs
+            .store(on, std::sync::atomic::Ordering::Release);
+    }
+
+    fn auto_dismiss_dialogs(&self) -> bool {
+        self.auto_dismiss_dialogs
+            .load(std::sync::atomic::Ordering::Acquire)
+    }
+
+    fn command_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.command_timeout_ms.load(Ordering::Relaxed))
+    }
+
     /// Execute a browser-level CDP command (no sessionId).
     pub async fn execute_browser(&self, method: &str, params: Value) -> Result<Value, CliError> {
         self.execute(method, params, None).await
@@ -1417,9 +1589,7 @@ impl CdpSession {
             ));
         }
 
-        // 60s covers slow operations (PDF, screenshot, large eval) while still
-        // catching genuinely hung connections that the old code waited on forever.
-        let resp = tokio::time::timeout(std::time::Duration::from_secs(60), rx)
+        let resp = tokio::time::timeout(self.command_timeout(), rx)
             .await
             .map_err(|_| {
                 // Clean up the pending entry on timeout to prevent leak.
@@ -1566,6 +1736,7 @@ impl CdpSession {
         tab_har_recorders: TabHarRecorders,
         writer_tx: mpsc::Sender<String>,
         next_id: Arc<AtomicU64>,
+        auto_dismiss_dialogs: Arc<std::sync::atomic::AtomicBool>,
     ) where
         S: StreamExt<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
     {
@@ -1879,9 +2050,49 @@ impl CdpSession {
                 // Route to external event subscribers keyed by "{sessionId}:{method}".
                 let key = format!("{session_id}:{method}");
                 let mut subs = event_subs.lock().await;
+                let mut delivered = false;
                 if let Some(txs) = subs.get_mut(&key) {
                     // try_send is non-blocking; retain removes closed receivers lazily.
                     txs.retain(|tx| tx.try_send(resp.clone()).is_ok());
+                    delivered = !txs.is_empty();
+                }
+                drop(subs);
+
+                // Nobody is waiting in `browser dialog`: dismiss so the tab
+                // doesn't block every later command. beforeunload is accepted
+                // so the navigation that raised it goes through.
+                if method == "Page.javascriptDialogOpening"
+                    && !delivered
+                    && auto_dismiss_dialogs.load(std::sync::atomic::Ordering::Acquire)
+                {
+                    let route = match ext_tab_key.as_deref() {
+                        Some(k) => k
+                            .strip_prefix("tab:")
+                            .and_then(|n| n.parse::<u64>().ok())
+                            .map(HarFetchRoute::ExtensionTab),
+                        None => Some(HarFetchRoute::FlatSession(session_id.to_string())),
+                    };
+                    if let Some(route) = route {
+                        let accept = resp.pointer("/params/type").and_then(|v| v.as_str())
+                            == Some("beforeunload");
+                        let pending_clone = pending.clone();
+                        let writer_tx_clone = writer_tx.clone();
+                        let next_id_clone = next_id.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = send_cdp_raw(
+                                &pending_clone,
+                                &writer_tx_clone,
+                                &next_id_clone,
+                                "Page.handleJavaScriptDialog",
+                                json!({ "accept": accept }),
+                                &route,
+                            )
+                            .await
+                            {
+                                tracing::warn!("dialog auto-dismiss failed: {e}");
+                            }
+                        });
+                    }
                 }
             }
         }
@@ -1910,13 +2121,42 @@ impl CdpSession {
     /// open; the peer never sees EOF and keeps us registered as "still
     /// connected", which breaks immediate reconnects (e.g. the extension
     /// bridge rejecting a second CDP client).
-    async fn writer_loop<S>(mut writer: S, mut rx: mpsc::Receiver<String>)
-    where
+    ///
+    /// Also stops when `dropped` fires because the peer went away: the
+    /// receiver is dropped first so senders fail fast, and anything queued
+    /// after reader_loop drained `pending` is failed here.
+    async fn writer_loop<S>(
+        mut writer: S,
+        mut rx: mpsc::Receiver<String>,
+        mut dropped: oneshot::Receiver<()>,
+        pending: PendingRequests,
+    ) where
         S: SinkExt<Message> + Unpin,
     {
-        while let Some(text) = rx.recv().await {
-            if writer.send(Message::Text(text.into())).await.is_err() {
-                return;
+        // Set once the reader is gone without signalling (aborted): a
+        // completed oneshot must not be polled again.
+        let mut reader_aborted = false;
+        loop {
+            tokio::select! {
+                msg = rx.recv() => {
+                    let Some(text) = msg else { break };
+                    if writer.send(Message::Text(text.into())).await.is_err() {
+                        return;
+                    }
+                }
+                signal = &mut dropped, if !reader_aborted => {
+                    if signal.is_err() {
+                        reader_aborted = true;
+                        continue;
+                    }
+                    drop(rx);
+                    for (_, tx) in pending.lock().await.drain() {
+                        let _ = tx.send(Err(CliError::SessionClosed(
+                            "session was closed while command was pending".to_string(),
+                        )));
+                    }
+                    return;
+                }
             }
         }
         // Graceful shutdown: send Close frame then close the sink.
@@ -2326,6 +2566,49 @@ mod tests {
         }
     }
 
+    // ── connect_with_retry ────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_connect_with_retry_recovers_when_server_comes_up() {
+        // Reserve a port, release it, and only start listening after the
+        // first attempt has failed.
+        let port = {
+            let l = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            l.local_addr().unwrap().port()
+        };
+        let url = format!("ws://127.0.0.1:{port}");
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(40)).await;
+            let listener = TcpListener::bind(("127.0.0.1", port)).await.unwrap();
+            let (stream, _) = listener.accept().await.unwrap();
+            let ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            // Hold the connection open until the client goes away.
+            let (_writer, mut reader) = ws.split();
+            while reader.next().await.is_some() {}
+        });
+
+        let cdp = CdpSession::connect_with_retry(&url, &[], MAX_TRACKED_REQUESTS, &[100, 200])
+            .await
+            .expect("should connect once the server is listening");
+        cdp.close().await;
+    }
+
+    #[tokio::test]
+    async fn test_connect_with_retry_gives_up_after_last_attempt() {
+        let port = {
+            let l = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            l.local_addr().unwrap().port()
+        };
+        let url = format!("ws://127.0.0.1:{port}");
+        let started = std::time::Instant::now();
+        let err = CdpSession::connect_with_retry(&url, &[], MAX_TRACKED_REQUESTS, &[20, 20])
+            .await
+            .err()
+            .expect("nothing is listening");
+        assert!(matches!(err, CliError::CdpConnectionFailed(_)), "{err}");
+        assert!(started.elapsed() >= std::time::Duration::from_millis(40));
+    }
+
     // ── 5. test_connection_drop ───────────────────────────────────────
 
     #[tokio::test]
@@ -2682,6 +2965,67 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_unhandled_dialog_is_auto_dismissed() {
+        let (url, mut conns) = mock_ws_server().await;
+        let cdp = CdpSession::connect(&url).await.unwrap();
+        cdp.set_auto_dismiss_dialogs(true);
+        let (mut reader, mut writer) = conns.recv().await.unwrap();
+
+        send_json(
+            &mut writer,
+            json!({
+                "method": "Page.javascriptDialogOpening",
+                "sessionId": "SESSION_DLG",
+                "params": { "type": "confirm", "message": "Leave?" }
+            }),
+        )
+        .await;
+
+        let msg = tokio::time::timeout(std::time::Duration::from_secs(2), read_json(&mut reader))
+            .await
+            .expect("dialog was not dismissed");
+        assert_eq!(msg["method"], "Page.handleJavaScriptDialog");
+        assert_eq!(msg["sessionId"], "SESSION_DLG");
+        assert_eq!(msg["params"]["accept"], false);
+    }
+
+    #[tokio::test]
+    async fn test_dialog_left_to_subscriber_when_waiting() {
+        let (url, mut conns) = mock_ws_server().await;
+        let cdp = CdpSession::connect(&url).await.unwrap();
+        cdp.set_auto_dismiss_dialogs(true);
+        let (mut reader, mut writer) = conns.recv().await.unwrap();
+
+        let mut rx = cdp
+            .subscribe_events("SESSION_DLG", "Page.javascriptDialogOpening")
+            .await;
+        send_json(
+            &mut writer,
+            json!({
+                "method": "Page.javascriptDialogOpening",
+                "sessionId": "SESSION_DLG",
+                "params": { "type": "alert", "message": "hi" }
+            }),
+        )
+        .await;
+
+        let event = tokio::time::timeout(std::time::Duration::from_secs(2), rx.recv())
+            .await
+            .expect("timed out waiting for event")
+            .expect("channel closed");
+        assert_eq!(event["params"]["message"], "hi");
+        assert!(
+            tokio::time::timeout(
+                std::time::Duration::from_millis(200),
+                read_json(&mut reader)
+            )
+            .await
+            .is_err(),
+            "a waiting `browser dialog` must get to handle the dialog itself"
+        );
+    }
+
     // ── 11. test_close_stops_background_tasks ────────────────────────
 
     #[tokio::test]
@@ -2780,6 +3124,28 @@ mod tests {
         );
     }
 
+    /// A per-session command timeout replaces the 60s default, and the
+    /// timed-out request still leaves the pending map.
+    #[tokio::test(start_paused = true)]
+    async fn test_execute_honors_command_timeout() {
+        let (url, mut conns) = mock_ws_server().await;
+        let cdp = CdpSession::connect(&url).await.unwrap();
+        let (_reader, _writer) = conns.recv().await.unwrap();
+        cdp.set_command_timeout_ms(2_000);
+
+        let started = tokio::time::Instant::now();
+        let result = cdp.execute("Test.noReply", json!({}), None).await;
+        let elapsed = started.elapsed();
+
+        assert!(matches!(result, Err(CliError::Timeout)), "{result:?}");
+        assert!(
+            elapsed < std::time::Duration::from_millis(DEFAULT_COMMAND_TIMEOUT_MS),
+            "should time out after ~2s, took {elapsed:?}"
+        );
+        tokio::task::yield_now().await;
+        assert!(cdp.pending.lock().await.is_empty());
+    }
+
     // ── 15. test_attach_propagates_network_enable_error ──────────────
 
     /// When Network.enable returns a CDP error during attach(), attach()
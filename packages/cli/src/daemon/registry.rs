@@ -5,7 +5,7 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 
 use crate::action_result::ActionResult;
-use crate::browser::observation::snapshot_transform::RefCache;
+use crate::browser::observation::snapshot_transform::{NodeEntry, RefCache};
 use crate::browser::session::provider::{ProviderSession, normalize_provider_name};
 use crate::daemon::bridge::SharedBridgeState;
 use crate::daemon::cdp_session::CdpSession;
@@ -86,6 +86,16 @@ pub struct SessionEntry {
     pub next_tab_id: u32,
     /// Maximum number of network requests tracked per tab (ring buffer cap).
     pub max_tracked_requests: usize,
+    /// Per-command response timeout set at start (extension mode), if any.
+    pub command_timeout_ms: Option<u64>,
+    /// Bridge connect retry count set at start (extension mode), if any.
+    pub connect_retries: Option<usize>,
+    /// Bridge heartbeat interval set at start (extension mode), if any.
+    pub heartbeat_interval_ms: Option<u64>,
+    /// Bridge client id of the extension this session drives (extension mode).
+    pub extension_id: Option<String>,
+    /// Whether unhandled JavaScript dialogs are dismissed automatically.
+    pub auto_dismiss_dialogs: bool,
 }
 
 impl Drop for SessionEntry {
@@ -127,6 +137,11 @@ impl SessionEntry {
             provider_session: None,
             next_tab_id: 1,
             max_tracked_requests: crate::daemon::cdp_session::MAX_TRACKED_REQUESTS,
+            command_timeout_ms: None,
+            connect_retries: None,
+            heartbeat_interval_ms: None,
+            extension_id: None,
+            auto_dismiss_dialogs: true,
         }
     }
 
@@ -190,11 +205,33 @@ impl SessionEntry {
     }
 }
 
+/// Most filter-option combinations a tab keeps `snapshot --diff` baselines
+/// for; the least recently taken one is dropped past this.
+const MAX_SNAPSHOT_BASELINES_PER_TAB: usize = 4;
+
+/// A tab's previous snapshot, the baseline for `snapshot --diff`.
+#[derive(Debug, Clone)]
+pub struct SnapshotBaseline {
+    pub nodes: Vec<NodeEntry>,
+    /// The tab navigated since: refs were reissued, so nodes can't be
+    /// matched to the current snapshot by ref.
+    pub navigated: bool,
+    /// Registry-wide insertion order, for evicting the oldest options.
+    seq: u64,
+}
+
 /// Thread-safe session registry.
 pub struct SessionRegistry {
     sessions: HashMap<String, SessionEntry>,
     /// Tab-scoped RefCache for stable snapshot refs. Key: "session_id\0tab_id"
     ref_caches: HashMap<String, RefCache>,
+    /// Last snapshot per tab and filter options, for `snapshot --diff`.
+    /// Key: "session_id\0tab_id\0options". Kept across navigation (marked
+    /// `navigated`), dropped with the tab or session. At most
+    /// `MAX_SNAPSHOT_BASELINES_PER_TAB` per tab.
+    last_snapshots: HashMap<String, SnapshotBaseline>,
+    /// Next `SnapshotBaseline::seq`.
+    snapshot_seq: u64,
     /// Last known cursor position per tab. Key: "session_id\0tab_id"
     cursor_positions: HashMap<String, (f64, f64)>,
     /// Extension bridge state. `None` until first lazy `ensure_bridge` call;
@@ -216,6 +253,8 @@ impl SessionRegistry {
         SessionRegistry {
             sessions: HashMap::new(),
             ref_caches: HashMap::new(),
+            last_snapshots: HashMap::new(),
+            snapshot_seq: 0,
             cursor_positions: HashMap::new(),
             bridge_state: None,
             bridge_init_lock: Arc::new(Mutex::new(())),
@@ -431,17 +470,74 @@ impl SessionRegistry {
         self.ref_caches.get(&key)
     }
 
+    /// Record a tab's latest snapshot taken with `options` (a key of the
+    /// filter flags), returning the previous one taken with the same options.
+    pub fn replace_last_snapshot(
+        &mut self,
+        session_id: &str,
+        tab_id: &str,
+        options: &str,
+        nodes: Vec<NodeEntry>,
+    ) -> Option<SnapshotBaseline> {
+        let prefix = format!("{}\0{}\0", session_id, tab_id);
+        let key = format!("{prefix}{options}");
+        self.snapshot_seq += 1;
+        let previous = self.last_snapshots.insert(
+            key,
+            SnapshotBaseline {
+                nodes,
+                navigated: false,
+                seq: self.snapshot_seq,
+            },
+        );
+
+        let mut tab_keys: Vec<(u64, String)> = self
+            .last_snapshots
+            .iter()
+            .filter(|(k, _)| k.starts_with(&prefix))
+            .map(|(k, b)| (b.seq, k.clone()))
+            .collect();
+        if tab_keys.len() > MAX_SNAPSHOT_BASELINES_PER_TAB {
+            tab_keys.sort();
+            for (_, k) in &tab_keys[..tab_keys.len() - MAX_SNAPSHOT_BASELINES_PER_TAB] {
+                self.last_snapshots.remove(k);
+            }
+        }
+        previous
+    }
+
     /// Clear the RefCache for a tab (call on navigation/reload/back/forward).
     /// When the page changes, old backendNodeIds are no longer valid.
+    /// Last snapshots are kept but marked `navigated`: their refs may be
+    /// reissued to other nodes.
     pub fn clear_ref_cache(&mut self, session_id: &str, tab_id: &str) {
         let key = format!("{}\0{}", session_id, tab_id);
         self.ref_caches.remove(&key);
+        let prefix = format!("{key}\0");
+        for (_, baseline) in self
+            .last_snapshots
+            .iter_mut()
+            .filter(|(k, _)| k.starts_with(&prefix))
+        {
+            baseline.navigated = true;
+        }
+    }
+
+    /// Drop everything kept for a tab: RefCache, snapshot baselines and
+    /// cursor position (call on tab close).
+    pub fn remove_tab_state(&mut self, session_id: &str, tab_id: &str) {
+        let key = format!("{}\0{}", session_id, tab_id);
+        self.ref_caches.remove(&key);
+        self.cursor_positions.remove(&key);
+        let prefix = format!("{key}\0");
+        self.last_snapshots.retain(|k, _| !k.starts_with(&prefix));
     }
 
     /// Clear all RefCaches for a session (call on session close/restart).
     pub fn clear_session_ref_caches(&mut self, session_id: &str) {
         let prefix = format!("{}\0", session_id);
         self.ref_caches.retain(|k, _| !k.starts_with(&prefix));
+        self.last_snapshots.retain(|k, _| !k.starts_with(&prefix));
     }
 
     /// Store the cursor position for a tab.
@@ -646,6 +742,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn last_snapshot_is_keyed_by_options_and_survives_navigation() {
+        let node = |r: &str| NodeEntry {
+            r#ref: r.to_string(),
+            role: "button".to_string(),
+            name: String::new(),
+            value: String::new(),
+            state: Default::default(),
+        };
+        let mut registry = SessionRegistry::new();
+        assert!(
+            registry
+                .replace_last_snapshot("s1", "t1", "all", vec![node("e1")])
+                .is_none()
+        );
+        assert!(
+            registry
+                .replace_last_snapshot("s1", "t1", "interactive", vec![node("e2")])
+                .is_none()
+        );
+
+        registry.clear_ref_cache("s1", "t1");
+        let previous = registry
+            .replace_last_snapshot("s1", "t1", "all", vec![node("e3")])
+            .unwrap();
+        assert_eq!(previous.nodes, [node("e1")]);
+        assert!(previous.navigated);
+        let previous = registry
+            .replace_last_snapshot("s1", "t1", "all", vec![node("e4")])
+            .unwrap();
+        assert!(!previous.navigated);
+
+        registry.clear_session_ref_caches("s1");
+        assert!(
+            registry
+                .replace_last_snapshot("s1", "t1", "interactive", Vec::new())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn snapshot_baselines_are_capped_per_tab_and_dropped_with_the_tab() {
+        let mut registry = SessionRegistry::new();
+        for i in 0..=MAX_SNAPSHOT_BASELINES_PER_TAB {
+            registry.replace_last_snapshot("s1", "t1", &format!("depth={i}"), Vec::new());
+        }
+        registry.replace_last_snapshot("s1", "t2", "all", Vec::new());
+        registry.set_cursor_position("s1", "t1", 1.0, 2.0);
+        assert_eq!(
+            registry.last_snapshots.len(),
+            MAX_SNAPSHOT_BASELINES_PER_TAB + 1
+        );
+        // The oldest options were evicted, the newest kept.
+        assert!(
+            registry
+                .replace_last_snapshot("s1", "t1", "depth=0", Vec::new())
+                .is_none()
+        );
+        assert!(
+            registry
+                .replace_last_snapshot(
+                    "s1",
+                    "t1",
+                    &format!("depth={MAX_SNAPSHOT_BASELINES_PER_TAB}"),
+                    Vec::new()
+                )
+                .is_some()
+        );
+
+        registry.remove_tab_state("s1", "t1");
+        assert_eq!(registry.last_snapshots.len(), 1);
+        assert!(registry.get_cursor_position("s1", "t1").is_none());
+        assert!(
+            registry
+                .replace_last_snapshot("s1", "t2", "all", Vec::new())
+                .is_some()
+        );
+    }
+
     #[test]
     fn has_active_sessions_empty_registry() {
         let registry = SessionRegistry::new();
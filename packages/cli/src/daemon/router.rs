@@ -3,16 +3,29 @@ use crate::action_result::ActionResult;
 use crate::browser;
 use crate::extension;
 
-use super::registry::SharedRegistry;
+use super::bridge::connect_retry_delays;
+use super::registry::{SessionState, SharedRegistry};
+use crate::types::Mode;
 
 /// Route an action to the appropriate handler.
 pub async fn route(action: &Action, registry: &SharedRegistry) -> ActionResult {
+    // Close/restart tear the connection down anyway; don't make them wait
+    // on a reconnect first.
+    if !matches!(action, Action::Close(_) | Action::Restart(_))
+        && let Some(session) = action.session_id()
+        && let Err(e) = reconnect_extension_session(registry, &session).await
+    {
+        return e;
+    }
+
     match action {
         Action::StartSession(cmd) => browser::session::start::execute(cmd, registry).await,
         Action::ListSessions(cmd) => browser::session::list::execute(cmd, registry).await,
         Action::SessionStatus(cmd) => browser::session::status::execute(cmd, registry).await,
         Action::Close(cmd) => browser::session::close::execute(cmd, registry).await,
         Action::Restart(cmd) => browser::session::restart::execute(cmd, registry).await,
+        Action::ProfilesList(cmd) => browser::profiles::list::execute(cmd, registry).await,
+        Action::ProfilesDelete(cmd) => browser::profiles::delete::execute(cmd, registry).await,
         Action::Goto(cmd) => browser::navigation::goto::execute(cmd, registry).await,
         Action::Back(cmd) => browser::navigation::back::execute(cmd, registry).await,
         Action::Forward(cmd) => browser::navigation::forward::execute(cmd, registry).await,
@@ -29,6 +42,8 @@ pub async fn route(action: &Action, registry: &SharedRegistry) -> ActionResult {
         Action::Title(cmd) => browser::observation::title::execute(cmd, registry).await,
         Action::Url(cmd) => browser::observation::url::execute(cmd, registry).await,
         Action::Viewport(cmd) => browser::observation::viewport::execute(cmd, registry).await,
+        Action::Emulate(cmd) => browser::emulation::emulate::execute(cmd, registry).await,
+        Action::Throttle(cmd) => browser::emulation::throttle::execute(cmd, registry).await,
         Action::Html(cmd) => browser::observation::html::execute(cmd, registry).await,
         Action::Text(cmd) => browser::observation::text::execute(cmd, registry).await,
         Action::Value(cmd) => browser::observation::value::execute(cmd, registry).await,
@@ -38,6 +53,8 @@ pub async fn route(action: &Action, registry: &SharedRegistry) -> ActionResult {
         Action::Styles(cmd) => browser::observation::styles::execute(cmd, registry).await,
         Action::Describe(cmd) => browser::observation::describe::execute(cmd, registry).await,
         Action::State(cmd) => browser::observation::state::execute(cmd, registry).await,
+        Action::Exists(cmd) => browser::observation::exists::execute(cmd, registry).await,
+        Action::Visible(cmd) => browser::observation::visible::execute(cmd, registry).await,
         Action::Query(cmd) => browser::observation::query::execute(cmd, registry).await,
         Action::InspectPoint(cmd) => {
             browser::observation::inspect_point::execute(cmd, registry).await
@@ -64,6 +81,8 @@ pub async fn route(action: &Action, registry: &SharedRegistry) -> ActionResult {
         Action::CookiesSet(cmd) => browser::cookies::set::execute(cmd, registry).await,
         Action::CookiesDelete(cmd) => browser::cookies::delete::execute(cmd, registry).await,
         Action::CookiesClear(cmd) => browser::cookies::clear::execute(cmd, registry).await,
+        Action::CookiesExport(cmd) => browser::cookies::export::execute(cmd, registry).await,
+        Action::CookiesImport(cmd) => browser::cookies::import::execute(cmd, registry).await,
         Action::StorageList(cmd) => browser::storage::list::execute(cmd, registry).await,
         Action::StorageGet(cmd) => browser::storage::get::execute(cmd, registry).await,
         Action::StorageSet(cmd) => browser::storage::set::execute(cmd, registry).await,
@@ -84,6 +103,7 @@ pub async fn route(action: &Action, registry: &SharedRegistry) -> ActionResult {
         Action::Select(cmd) => browser::interaction::select::execute(cmd, registry).await,
         Action::Drag(cmd) => browser::interaction::drag::execute(cmd, registry).await,
         Action::Upload(cmd) => browser::interaction::upload::execute(cmd, registry).await,
+        Action::Dialog(cmd) => browser::interaction::dialog::execute(cmd, registry).await,
         Action::MouseMove(cmd) => browser::interaction::mouse_move::execute(cmd, registry).await,
         Action::CursorPosition(cmd) => {
             browser::interaction::cursor_position::execute(cmd, registry).await
@@ -92,3 +112,163 @@ pub async fn route(action: &Action, registry: &SharedRegistry) -> ActionResult {
         Action::ExtensionStatus(cmd) => extension::status::execute_daemon(cmd, registry).await,
     }
 }
+
+/// An extension session's CdpSession dies with its bridge socket (bridge
+/// restart, daemon-side hiccup). Before running the next command on it,
+/// reconnect to the bridge with the session's `--connect-retries` schedule
+/// and re-attach its tabs, so a brief outage doesn't end the session.
+async fn reconnect_extension_session(
+    registry: &SharedRegistry,
+    session_id: &str,
+) -> Result<(), ActionResult> {
+    let (cdp, ws_url, retries) = {
+        let reg = registry.lock().await;
+        let Some(entry) = reg.get(session_id) else {
+            return Ok(());
+        };
+        if entry.mode != Mode::Extension || entry.status != SessionState::Running {
+            return Ok(());
+        }
+        let Some(cdp) = entry.cdp.clone() else {
+            return Ok(());
+        };
+        (cdp, entry.ws_url.clone(), entry.connect_retries)
+    };
+    if cdp.is_connected().await {
+        return Ok(());
+    }
+
+    tracing::warn!("session {session_id}: extension bridge connection lost — reconnecting");
+    cdp.reconnect(&ws_url, &connect_retry_delays(retries))
+        .await
+        .map_err(|e| {
+            ActionResult::fatal_with_hint(
+                "CDP_CONNECTION_FAILED",
+                format!("lost the extension bridge connection and could not reconnect: {e}"),
+                "check `actionbook extension status`, then retry or run `actionbook browser restart`",
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::browser::observation::url;
+    use crate::daemon::cdp_session::CdpSession;
+    use crate::daemon::registry::{self, SessionEntry};
+    use crate::types::SessionId;
+    use futures_util::{SinkExt, StreamExt};
+    use serde_json::json;
+    use tokio::net::TcpListener;
+    use tokio::sync::oneshot;
+    use tokio_tungstenite::tungstenite::Message;
+
+    const PAGE_URL: &str = "https://example.com/";
+
+    /// Mock bridge + extension: serves one CDP client, answering
+    /// `Runtime.evaluate` with `PAGE_URL` and everything else with `{}`,
+    /// until `stop` fires — then drops the socket like a restarting bridge.
+    /// Returns the methods it received.
+    fn serve_bridge(
+        listener: TcpListener,
+        stop: oneshot::Receiver<()>,
+    ) -> tokio::task::JoinHandle<Vec<String>> {
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let (mut writer, mut reader) = ws.split();
+            let mut seen = Vec::new();
+            tokio::pin!(stop);
+            loop {
+                let raw = tokio::select! {
+                    raw = reader.next() => raw,
+                    _ = &mut stop => break,
+                };
+                let Some(Ok(Message::Text(t))) = raw else {
+                    break;
+                };
+                let msg: serde_json::Value = serde_json::from_str(t.as_ref()).unwrap();
+                let method = msg["method"].as_str().unwrap().to_string();
+                let result = match method.as_str() {
+                    "Runtime.evaluate" => json!({ "result": { "value": PAGE_URL } }),
+                    _ => json!({}),
+                };
+                seen.push(method);
+                let reply = json!({ "id": msg["id"], "result": result }).to_string();
+                if writer.send(Message::Text(reply.into())).await.is_err() {
+                    break;
+                }
+            }
+            seen
+        })
+    }
+
+    async fn url_of(registry: &SharedRegistry) -> ActionResult {
+        let cmd = url::Cmd {
+            session: "s1".to_string(),
+            tab: "t1".to_string(),
+        };
+        route(&Action::Url(cmd), registry).await
+    }
+
+    #[tokio::test]
+    async fn extension_session_reconnects_after_bridge_restart() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let ws_url = format!("ws://{addr}");
+        let (stop_first, stop) = oneshot::channel();
+        let first = serve_bridge(listener, stop);
+
+        let cdp = CdpSession::connect(&ws_url).await.unwrap();
+        cdp.register_extension_tab("100").await;
+        let registry = registry::new_shared_registry();
+        {
+            let mut entry = SessionEntry::starting(
+                SessionId::new_unchecked("s1"),
+                Mode::Extension,
+                false,
+                true,
+                "default".to_string(),
+            );
+            entry.status = SessionState::Running;
+            entry.ws_url = ws_url.clone();
+            entry.cdp = Some(cdp.clone());
+            entry.push_tab("100".to_string(), String::new(), String::new());
+            registry.lock().await.insert(entry);
+        }
+        let ActionResult::Ok { data } = url_of(&registry).await else {
+            panic!("url before restart failed");
+        };
+        assert_eq!(data["value"], PAGE_URL);
+
+        // Bridge goes away, and comes back on the same port a little later —
+        // within the default retry schedule.
+        stop_first.send(()).unwrap();
+        first.await.unwrap();
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(3);
+        while cdp.is_connected().await {
+            assert!(std::time::Instant::now() < deadline, "drop not detected");
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        let (_stop_second, stop) = oneshot::channel();
+        let second = tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(150)).await;
+            serve_bridge(TcpListener::bind(addr).await.unwrap(), stop)
+                .await
+                .unwrap()
+        });
+
+        let ActionResult::Ok { data } = url_of(&registry).await else {
+            panic!("url after bridge restart failed");
+        };
+        assert_eq!(data["value"], PAGE_URL);
+
+        cdp.close().await;
+        let seen = second.await.unwrap();
+        assert_eq!(
+            seen.first().map(String::as_str),
+            Some("Extension.attachTab")
+        );
+        assert!(seen.iter().any(|m| m == "Runtime.evaluate"));
+    }
+}
@@ -18,7 +18,7 @@ pub async fn execute_daemon(_cmd: &Cmd, registry: &SharedRegistry) -> ActionResu
         reg.bridge_state().cloned()
     };
 
-    let (bridge, extension_connected) = match bridge_arc {
+    let (bridge, extension_connected, extension_ids) = match bridge_arc {
         Some(state) => {
             let state = state.lock().await;
             let bridge_str = match state.listener_status() {
@@ -26,13 +26,18 @@ pub async fn execute_daemon(_cmd: &Cmd, registry: &SharedRegistry) -> ActionResu
                 BridgeListenerStatus::Failed => "failed",
                 BridgeListenerStatus::Binding => "not_listening",
             };
-            (bridge_str, state.is_extension_connected())
+            (
+                bridge_str,
+                state.is_extension_connected(),
+                state.extension_ids(),
+            )
         }
-        None => ("not_listening", false),
+        None => ("not_listening", false, Vec::new()),
     };
 
     ActionResult::ok(json!({
         "bridge": bridge,
         "extension_connected": extension_connected,
+        "extension_ids": extension_ids,
     }))
 }
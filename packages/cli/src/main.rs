@@ -1,7 +1,7 @@
 use std::io::Write as _;
 use std::time::{Duration, Instant};
 
-use clap::Parser;
+use clap::{CommandFactory, Parser};
 use serde_json::json;
 
 use actionbook_cli::action::Action;
@@ -60,6 +60,7 @@ async fn main() {
     {
         let raw_args: Vec<String> = std::env::args().collect();
         let json_mode = raw_args.iter().any(|a| a == "--json");
+        output::set_pretty_json(raw_args.iter().any(|a| a == "--pretty"));
 
         // Collect non-flag args after the binary name, skipping --timeout's value
         let mut positional_args: Vec<&str> = Vec::new();
@@ -101,9 +102,17 @@ async fn main() {
         }
     }
 
-    let cli = Cli::parse();
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(e) => handle_parse_error(e),
+    };
     let json_output = cli.json;
+    output::set_pretty_json(cli.pretty);
     let is_setup_command = matches!(cli.command.as_ref(), Some(Commands::Setup(_)));
+    let error_exit_code = match cli.command.as_ref() {
+        Some(Commands::Browser { command }) => failure_exit_code(command.command_name()),
+        _ => 1,
+    };
 
     // Handle --version before subcommand dispatch
     if cli.version {
@@ -147,16 +156,94 @@ async fn main() {
                     &hint,
                     std::time::Duration::ZERO,
                 );
-                println!("{}", serde_json::to_string(&envelope).unwrap_or_default());
+                println!("{}", output::to_json_string(&envelope).unwrap_or_default());
             } else {
                 eprintln!("error {code}: {e}");
                 if !hint.is_empty() {
                     eprintln!("hint: {hint}");
                 }
             }
-            flush_and_exit(1);
+            flush_and_exit(error_exit_code);
+        }
+    }
+}
+
+/// Report a clap argument error. Under `--json` the error becomes an
+/// `INVALID_ARGUMENT` envelope on stdout instead of clap's stderr text.
+fn handle_parse_error(e: clap::Error) -> ! {
+    use clap::error::ErrorKind;
+
+    let json_mode = std::env::args().any(|a| a == "--json");
+    if !json_mode || matches!(e.kind(), ErrorKind::DisplayHelp | ErrorKind::DisplayVersion) {
+        e.exit();
+    }
+
+    // Keep clap's description, drop its tips and the trailing usage block.
+    let rendered = e.to_string();
+    let message = rendered
+        .lines()
+        .take_while(|line| !line.starts_with("Usage:"))
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("tip:"))
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_start_matches("error: ")
+        .to_string();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = parsed_command_path(&args).unwrap_or_else(|| "unknown".to_string());
+    let envelope = JsonEnvelope::error(
+        &command,
+        None,
+        "INVALID_ARGUMENT",
+        &message,
+        false,
+        json!({ "kind": format!("{:?}", e.kind()) }),
+        "run the command with --help for usage",
+        std::time::Duration::ZERO,
+    );
+    println!("{}", output::to_json_string(&envelope).unwrap_or_default());
+    flush_and_exit(e.exit_code());
+}
+
+/// Subcommand path clap walked before it failed (e.g. `browser click`), read
+/// off the raw args since `clap::Error` doesn't carry it. Options are skipped
+/// along with their value; the walk stops at the first positional.
+fn parsed_command_path(args: &[String]) -> Option<String> {
+    let mut root = Cli::command();
+    root.build();
+    let mut cmd = &root;
+    let mut path = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            break;
+        }
+        let takes_value = if let Some(long) = arg.strip_prefix("--") {
+            !long.contains('=')
+                && cmd
+                    .get_arguments()
+                    .any(|a| a.get_long() == Some(long) && a.get_action().takes_values())
+        } else if let Some(short) = arg.strip_prefix('-')
+            && !short.is_empty()
+        {
+            let mut chars = short.chars();
+            chars.next().is_some_and(|c| {
+                cmd.get_arguments()
+                    .any(|a| a.get_short() == Some(c) && a.get_action().takes_values())
+            }) && chars.as_str().is_empty()
+        } else {
+            let Some(sub) = cmd.find_subcommand(arg) else {
+                break;
+            };
+            path.push(sub.get_name().to_string());
+            cmd = sub;
+            continue;
+        };
+        if takes_value {
+            args.next();
         }
     }
+    (!path.is_empty()).then(|| path.join(" "))
 }
 
 async fn run(mut cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
@@ -191,7 +278,7 @@ async fn run(mut cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
             handle_daemon(command, json_mode, timeout_ms).await?;
         }
         Commands::Extension { command } => {
-            handle_extension(command, json_mode).await?;
+            handle_extension(command, json_mode, timeout_ms).await?;
         }
         Commands::Setup(cmd) => {
             actionbook_cli::setup::execute(&cmd, json_mode).await?;
@@ -215,21 +302,25 @@ async fn handle_browser(
         handle_browser_help(json_mode);
         return Ok(());
     }
+    if matches!(command, BrowserCommands::Schema) {
+        handle_browser_schema(json_mode)?;
+        return Ok(());
+    }
 
     let start = Instant::now();
     let command = match command {
-        BrowserCommands::Start(cmd) => match config::resolve_start_command(cmd) {
+        BrowserCommands::Start(cmd) => match config::resolve_start_command(*cmd) {
             Ok(mut cmd) => {
                 // Forward provider env vars from the CLI client's process env
                 // to the daemon. The daemon's own env was frozen at spawn time
                 // and can't be relied on to match the user's current shell.
                 cmd.provider_env =
                     actionbook_cli::browser::session::provider::collect_provider_env_from_process();
-                BrowserCommands::Start(cmd)
+                BrowserCommands::Start(Box::new(cmd))
             }
             Err(err) => {
-                let failed_command =
-                    BrowserCommands::Start(actionbook_cli::browser::session::start::Cmd {
+                let failed_command = BrowserCommands::Start(Box::new(
+                    actionbook_cli::browser::session::start::Cmd {
                         mode: None,
                         headless: None,
                         profile: None,
@@ -242,16 +333,22 @@ async fn handle_browser(
                         session: None,
                         set_session_id: None,
                         stealth: true,
+                        auto_dismiss_dialogs: true,
                         max_tracked_requests: 500,
+                        command_timeout: None,
+                        connect_retries: None,
+                        heartbeat_interval: None,
+                        extension_id: None,
                         provider_env: Default::default(),
-                    });
+                    },
+                ));
                 let result = ActionResult::fatal(err.error_code(), err.to_string());
                 let duration = start.elapsed();
                 let context = failed_command.context(&result);
                 if json_mode {
                     let envelope =
                         JsonEnvelope::from_result("browser start", context, &result, duration);
-                    println!("{}", serde_json::to_string(&envelope)?);
+                    println!("{}", output::to_json_string(&envelope)?);
                 } else {
                     let text = output::format_text("browser start", &context, &result);
                     eprintln!("{text}");
@@ -284,7 +381,7 @@ async fn handle_browser(
                     if json_mode {
                         let envelope =
                             JsonEnvelope::from_result(&command_name, context, &result, duration);
-                        println!("{}", serde_json::to_string(&envelope)?);
+                        println!("{}", output::to_json_string(&envelope)?);
                     } else {
                         let text = output::format_text(&command_name, &context, &result);
                         eprintln!("{text}");
@@ -310,7 +407,7 @@ async fn handle_browser(
             let context = command.context(&result);
             if json_mode {
                 let envelope = JsonEnvelope::from_result(&command_name, context, &result, duration);
-                println!("{}", serde_json::to_string(&envelope)?);
+                println!("{}", output::to_json_string(&envelope)?);
             } else {
                 let text = output::format_text(&command_name, &context, &result);
                 eprintln!("{text}");
@@ -350,12 +447,12 @@ async fn handle_browser(
                 if json_mode {
                     let envelope =
                         JsonEnvelope::from_result(&command_name, context, &result, duration);
-                    println!("{}", serde_json::to_string(&envelope)?);
+                    println!("{}", output::to_json_string(&envelope)?);
                 } else {
                     let text = output::format_text(&command_name, &context, &result);
                     eprintln!("{text}");
                 }
-                flush_and_exit(1);
+                flush_and_exit(failure_exit_code(&command_name));
             }
         }
     } else {
@@ -369,7 +466,7 @@ async fn handle_browser(
 
     if json_mode {
         let envelope = JsonEnvelope::from_result(&command_name, context.clone(), &result, duration);
-        println!("{}", serde_json::to_string(&envelope)?);
+        println!("{}", output::to_json_string(&envelope)?);
     } else {
         let text = output::format_text(&command_name, &context, &result);
         if result.is_ok() {
@@ -380,12 +477,28 @@ async fn handle_browser(
     }
 
     if !result.is_ok() {
+        flush_and_exit(failure_exit_code(&command_name));
+    }
+    if is_check_command(&command_name)
+        && matches!(&result, ActionResult::Ok { data } if data.get("value") == Some(&serde_json::Value::Bool(false)))
+    {
         flush_and_exit(1);
     }
 
     Ok(())
 }
 
+/// `exists`/`visible` use grep-style exit codes so shell scripts can branch on
+/// them: 0 true, 1 false, 2 error.
+fn is_check_command(command_name: &str) -> bool {
+    matches!(command_name, "browser exists" | "browser visible")
+}
+
+/// Exit code for a command that failed: 2 for checks, where 1 means `false`.
+fn failure_exit_code(command_name: &str) -> i32 {
+    if is_check_command(command_name) { 2 } else { 1 }
+}
+
 async fn handle_daemon(
     command: DaemonCommands,
     json_mode: bool,
@@ -419,7 +532,7 @@ async fn handle_daemon(
                             json!({ "status": "restarted" }),
                             duration,
                         );
-                        println!("{}", serde_json::to_string(&envelope)?);
+                        println!("{}", output::to_json_string(&envelope)?);
                     } else {
                         println!("daemon restarted");
                     }
@@ -429,7 +542,7 @@ async fn handle_daemon(
                         let result = ActionResult::fatal("DAEMON_RESTART_FAILED", e.to_string());
                         let envelope =
                             JsonEnvelope::from_result("daemon restart", None, &result, duration);
-                        println!("{}", serde_json::to_string(&envelope)?);
+                        println!("{}", output::to_json_string(&envelope)?);
                     } else {
                         eprintln!("error DAEMON_RESTART_FAILED: {e}");
                     }
@@ -444,48 +557,59 @@ async fn handle_daemon(
 async fn handle_extension(
     command: ExtensionCommands,
     json_mode: bool,
+    timeout_ms: Option<u64>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let start = Instant::now();
 
-    let (command_name, result) = match command {
-        ExtensionCommands::Status => {
-            let action = Action::ExtensionStatus(actionbook_cli::extension::status::Cmd::default());
-            let mut client = DaemonClient::connect().await?;
-            let result = client.send_action(&action).await?;
-            (actionbook_cli::extension::status::COMMAND_NAME, result)
-        }
-        ExtensionCommands::Ping => {
-            let result = actionbook_cli::extension::ping::execute().await;
-            (actionbook_cli::extension::ping::COMMAND_NAME, result)
-        }
-        ExtensionCommands::Path => {
-            let result = actionbook_cli::extension::installer::execute_path();
-            (
-                actionbook_cli::extension::installer::COMMAND_NAME_PATH,
-                result,
-            )
-        }
-        ExtensionCommands::Install(args) => {
-            let result = actionbook_cli::extension::installer::execute_install(args.force);
-            (
-                actionbook_cli::extension::installer::COMMAND_NAME_INSTALL,
-                result,
-            )
-        }
+    let command_name = match command {
+        ExtensionCommands::Status => actionbook_cli::extension::status::COMMAND_NAME,
+        ExtensionCommands::Ping => actionbook_cli::extension::ping::COMMAND_NAME,
+        ExtensionCommands::Path => actionbook_cli::extension::installer::COMMAND_NAME_PATH,
+        ExtensionCommands::Install(_) => actionbook_cli::extension::installer::COMMAND_NAME_INSTALL,
         ExtensionCommands::Uninstall => {
-            let result = actionbook_cli::extension::installer::execute_uninstall();
-            (
-                actionbook_cli::extension::installer::COMMAND_NAME_UNINSTALL,
-                result,
-            )
+            actionbook_cli::extension::installer::COMMAND_NAME_UNINSTALL
         }
     };
 
+    let execution = async {
+        let result = match command {
+            ExtensionCommands::Status => {
+                let action =
+                    Action::ExtensionStatus(actionbook_cli::extension::status::Cmd::default());
+                let mut client = DaemonClient::connect().await?;
+                client.send_action(&action).await?
+            }
+            ExtensionCommands::Ping => actionbook_cli::extension::ping::execute().await,
+            ExtensionCommands::Path => actionbook_cli::extension::installer::execute_path(),
+            ExtensionCommands::Install(args) => {
+                actionbook_cli::extension::installer::execute_install(args.force)
+            }
+            ExtensionCommands::Uninstall => {
+                actionbook_cli::extension::installer::execute_uninstall()
+            }
+        };
+        Ok::<_, actionbook_cli::error::CliError>(result)
+    };
+
+    // Same contract as browser commands: --timeout bounds the whole request,
+    // including the daemon round-trip for `extension status`.
+    let result = match timeout_ms {
+        Some(ms) => match tokio::time::timeout(Duration::from_millis(ms), execution).await {
+            Ok(result) => result?,
+            Err(_) => ActionResult::fatal_with_hint(
+                "TIMEOUT",
+                format!("{command_name} timed out after {ms}ms"),
+                "increase --timeout or retry the command",
+            ),
+        },
+        None => execution.await?,
+    };
+
     let duration = start.elapsed();
 
     if json_mode {
         let envelope = JsonEnvelope::from_result(command_name, None, &result, duration);
-        println!("{}", serde_json::to_string(&envelope)?);
+        println!("{}", output::to_json_string(&envelope)?);
     } else {
         let text = output::format_text(command_name, &None, &result);
         if result.is_ok() {
@@ -507,7 +631,7 @@ fn handle_version(json_mode: bool) {
     if json_mode {
         let envelope =
             JsonEnvelope::success("version", None, json!(version), std::time::Duration::ZERO);
-        println!("{}", serde_json::to_string(&envelope).unwrap_or_default());
+        println!("{}", output::to_json_string(&envelope).unwrap_or_default());
     } else {
         println!("{version}");
     }
@@ -534,6 +658,7 @@ Commands:
 
 Global flags:
   --json          Output as JSON envelope
+  --pretty        Indent JSON output (compact by default)
   --timeout <ms>  Set command timeout
 
 Quick start:
@@ -549,12 +674,26 @@ Run actionbook browser --help to see all browser subcommands.";
     if json_mode {
         let envelope =
             JsonEnvelope::success("help", None, json!(help_text), std::time::Duration::ZERO);
-        println!("{}", serde_json::to_string(&envelope).unwrap_or_default());
+        println!("{}", output::to_json_string(&envelope).unwrap_or_default());
     } else {
         println!("{help_text}");
     }
 }
 
+/// `browser schema` is static metadata, so it never reaches the daemon. The
+/// schema is JSON either way; `--json` wraps it in the usual envelope.
+fn handle_browser_schema(json_mode: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let schema = serde_json::to_value(actionbook_cli::browser::schema::browser_schema())?;
+    if json_mode {
+        let envelope =
+            JsonEnvelope::success("browser schema", None, schema, std::time::Duration::ZERO);
+        println!("{}", output::to_json_string(&envelope)?);
+    } else {
+        println!("{}", serde_json::to_string_pretty(&schema)?);
+    }
+    Ok(())
+}
+
 fn handle_browser_help(json_mode: bool) {
     let help_text = "\
 Usage: actionbook browser <subcommand> [options]
@@ -568,6 +707,8 @@ Session:
   status              --session      Show session status
   close               --session      Close a session (alias: stop)
   restart             --session      Restart a session
+  profiles list                      List local profiles and their running status
+  profiles delete <name> [--yes]     Delete a local profile
 
 Tab:
   list-tabs           --session      List tabs in a session
@@ -578,24 +719,29 @@ Navigation:
   goto <url>          --session --tab  Navigate to a URL
   back                --session --tab  Go back
   forward             --session --tab  Go forward
-  reload              --session --tab  Reload the page
+  reload [--hard]     --session --tab  Reload the page (--hard bypasses cache)
 
 Observation:
   snapshot            --session --tab  Capture accessibility snapshot
   screenshot <path>   --session --tab  Take a screenshot
   title               --session --tab  Get page title
   url                 --session --tab  Get current URL
-  viewport            --session --tab  Get viewport size
+  viewport [--set WxH] --session --tab Get (or set and save) viewport size
+  emulate <device>    --session --tab  Emulate a device or set viewport size
+  throttle <preset>   --session --tab  Throttle network (slow-3g, fast-3g, offline, none)
   html [<selector>]   --session --tab  Read element/page HTML
   text [<selector>]   --session --tab  Read element/page text
   value <selector>    --session --tab  Read input value
-  attr <selector> <name>  --session --tab  Read element attribute
+  attr <selector> <name> [--property]  --session --tab  Read element attribute or property
   attrs <selector>        --session --tab  Read all element attributes
   box <selector>          --session --tab  Read element bounding box
   styles <selector> [names...]  --session --tab  Read computed styles
   describe <selector>     --session --tab  Describe element properties
   state <selector>        --session --tab  Get element state flags
+  exists <selector>       --session --tab  Check element exists (exit 1 if not)
+  visible <selector>      --session --tab  Check element is visible (exit 1 if not)
   inspect-point <x,y>    --session --tab  Inspect element at coordinates
+  inspect-point --selector <sel>  --session --tab  Inspect element by selector
   query one|all|count <selector>  --session --tab  Query elements
   query nth <n> <selector>        --session --tab  Query nth element (1-based)
 
@@ -612,7 +758,7 @@ Network:
 Wait:
   wait element <selector>  --session --tab  Wait for element to appear
   wait navigation          --session --tab  Wait for navigation to complete
-  wait network-idle        --session --tab  Wait for network to become idle
+  wait network-idle [--idle <ms>]  --session --tab  Wait for network to become idle
   wait condition <expr>    --session --tab  Wait for JS expression to be truthy
 
 Cookies:
@@ -621,24 +767,28 @@ Cookies:
   cookies set <name> <value>  --session  Set a cookie
   cookies delete <name>  --session   Delete a cookie
   cookies clear       --session      Clear cookies
+  cookies export <path>  --session   Export cookies to a JSON file
+  cookies import <path>  --session   Import cookies from a JSON file
 
 Storage (local-storage | session-storage):
   <storage> list      --session --tab  List all key-value entries
   <storage> get <key> --session --tab  Get a value by key
   <storage> set <key> <value>  --session --tab  Set a key-value entry
   <storage> delete <key>  --session --tab  Delete a key
-  <storage> clear <key>   --session --tab  Clear a key
+  <storage> clear [<key>] --session --tab  Clear a key, or all keys
 
 Interaction:
   click <selector|x,y>   --session --tab  Click element or coordinates
+  click --text <label>   --session --tab  Click the element with matching visible text
   hover <selector>        --session --tab  Hover over an element
   focus <selector>        --session --tab  Focus an element
-  press <key>             --session --tab  Press a key or key combo
+  press <key>             --session --tab  Press a key, key combo, or sequence
   type <text>             --session --tab  Type text keystroke by keystroke
   fill <selector> <text>  --session --tab  Fill an input field directly
   select <selector> <value>  --session --tab  Select from a dropdown
   drag <source> <target>  --session --tab  Drag element to a target
   upload <selector> <file...>  --session --tab  Upload files to a file input
+  dialog <accept|dismiss> --session --tab  Accept or dismiss a JS dialog
   eval <code>             --session --tab  Evaluate JavaScript
   mouse-move <x,y>       --session --tab  Move mouse to coordinates
   cursor-position         --session --tab  Get current cursor position
@@ -649,8 +799,12 @@ Batch:
   batch-snapshot --tabs <tab...>  --session  Snapshot multiple tabs
   batch-click <sel...>  --session --tab  Click multiple elements sequentially
 
+Meta:
+  schema                             Print every command's parameters as JSON
+
 Global flags (apply to all subcommands):
   --json          Output as JSON envelope
+  --pretty        Indent JSON output (compact by default)
   --timeout <ms>  Set command timeout
 
 Quick start:
@@ -670,7 +824,7 @@ Run actionbook browser <subcommand> --help for full usage and examples.";
             json!(help_text),
             std::time::Duration::ZERO,
         );
-        println!("{}", serde_json::to_string(&envelope).unwrap_or_default());
+        println!("{}", output::to_json_string(&envelope).unwrap_or_default());
     } else {
         println!("{help_text}");
     }
@@ -1,8 +1,11 @@
 use serde::Serialize;
 use serde_json::Value;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 use crate::action_result::ActionResult;
+use crate::browser::observation::inspect_point::{InspectResult, Point};
+use crate::browser::observation::snapshot_transform::{NodeEntry, SnapshotDiff};
 
 /// §2.4 JSON envelope.
 #[derive(Debug, Serialize)]
@@ -161,6 +164,22 @@ impl JsonEnvelope {
     }
 }
 
+static PRETTY_JSON: AtomicBool = AtomicBool::new(false);
+
+/// Select indented JSON for every `--json` print (set once from `--pretty`).
+pub fn set_pretty_json(pretty: bool) {
+    PRETTY_JSON.store(pretty, Ordering::Relaxed);
+}
+
+/// Serialize a `--json` payload: compact by default, indented under `--pretty`.
+pub fn to_json_string<T: Serialize + ?Sized>(value: &T) -> serde_json::Result<String> {
+    if PRETTY_JSON.load(Ordering::Relaxed) {
+        serde_json::to_string_pretty(value)
+    } else {
+        serde_json::to_string(value)
+    }
+}
+
 /// Format text output per §2.5.
 pub fn format_text(
     command: &str,
@@ -207,6 +226,9 @@ pub fn format_text(
                     | "browser select"
                     | "browser drag"
                     | "browser upload"
+                    | "browser dialog"
+                    | "browser emulate"
+                    | "browser throttle"
                     | "browser mouse-move"
                     | "browser cursor-position"
                     | "browser scroll"
@@ -220,6 +242,8 @@ pub fn format_text(
                     | "browser cookies set"
                     | "browser cookies delete"
                     | "browser cookies clear"
+                    | "browser cookies export"
+                    | "browser cookies import"
                     | "browser local-storage set"
                     | "browser local-storage delete"
                     | "browser local-storage clear"
@@ -282,6 +306,66 @@ pub fn format_text(
     lines.join("\n")
 }
 
+/// `role "name" [ref=eN]`, matching the snapshot content lines.
+fn snapshot_node_label(node: &NodeEntry) -> String {
+    if node.name.is_empty() {
+        format!("{} [ref={}]", node.role, node.r#ref)
+    } else {
+        format!("{} \"{}\" [ref={}]", node.role, node.name, node.r#ref)
+    }
+}
+
+/// Text lines for `snapshot --diff`: a summary, then `+`/`-`/`~` per node.
+fn snapshot_diff_lines(diff: &SnapshotDiff, has_baseline: bool) -> Vec<String> {
+    if !has_baseline {
+        return vec![
+            "diff: no previous snapshot for this tab; this one is the baseline".to_string(),
+        ];
+    }
+    let mut lines = vec![format!(
+        "diff: {} added, {} removed, {} changed",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.changed.len()
+    )];
+    lines.extend(
+        diff.added
+            .iter()
+            .map(|n| format!("+ {}", snapshot_node_label(n))),
+    );
+    lines.extend(
+        diff.removed
+            .iter()
+            .map(|n| format!("- {}", snapshot_node_label(n))),
+    );
+    for change in &diff.changed {
+        let (before, after) = (&change.before, &change.after);
+        let mut parts = Vec::new();
+        if before.role != after.role {
+            parts.push(format!("role {} -> {}", before.role, after.role));
+        }
+        if before.name != after.name {
+            parts.push(format!("name \"{}\" -> \"{}\"", before.name, after.name));
+        }
+        if before.value != after.value {
+            parts.push(format!("value \"{}\" -> \"{}\"", before.value, after.value));
+        }
+        if before.state != after.state {
+            parts.push(format!(
+                "state [{}] -> [{}]",
+                before.state.set_flags().join(", "),
+                after.state.set_flags().join(", ")
+            ));
+        }
+        lines.push(format!(
+            "~ {}: {}",
+            snapshot_node_label(after),
+            parts.join("; ")
+        ));
+    }
+    lines
+}
+
 fn format_data_fields(command: &str, data: &Value, lines: &mut Vec<String>) {
     match command {
         "browser start" => {
@@ -333,6 +417,49 @@ fn format_data_fields(command: &str, data: &Value, lines: &mut Vec<String>) {
                 }
             }
         }
+        "browser profiles list" => {
+            let total = data
+                .get("total_profiles")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0);
+            let label = if total == 1 { "profile" } else { "profiles" };
+            lines.insert(0, format!("{total} {label}"));
+            if let Some(profiles) = data.get("profiles").and_then(|v| v.as_array()) {
+                for p in profiles {
+                    let name = p.get("name").and_then(|v| v.as_str()).unwrap_or("?");
+                    lines.push(format!("[{name}]"));
+                    let sessions: Vec<&str> = p
+                        .get("sessions")
+                        .and_then(|v| v.as_array())
+                        .map(|a| a.iter().filter_map(|s| s.as_str()).collect())
+                        .unwrap_or_default();
+                    if sessions.is_empty() {
+                        lines.push("status: idle".to_string());
+                    } else {
+                        lines.push(format!("status: running ({})", sessions.join(", ")));
+                    }
+                    if let Some(port) = p.get("cdp_port").and_then(|v| v.as_u64()) {
+                        lines.push(format!("cdp_port: {port}"));
+                    }
+                    if let Some(last_used) = p.get("last_used").and_then(|v| v.as_str()) {
+                        lines.push(format!("last_used: {last_used}"));
+                    }
+                }
+            }
+        }
+        "browser profiles delete" => {
+            if let Some(name) = data.get("name").and_then(|v| v.as_str()) {
+                lines.push(format!("deleted profile {name}"));
+            }
+            if let Some(closed) = data
+                .get("closed_sessions")
+                .and_then(|v| v.as_array())
+                .filter(|a| !a.is_empty())
+            {
+                let ids: Vec<&str> = closed.iter().filter_map(|s| s.as_str()).collect();
+                lines.push(format!("closed sessions: {}", ids.join(", ")));
+            }
+        }
         "browser status" => {
             if let Some(s) = data.get("session") {
                 if let Some(status) = s.get("status").and_then(|v| v.as_str()) {
@@ -358,6 +485,12 @@ fn format_data_fields(command: &str, data: &Value, lines: &mut Vec<String>) {
             {
                 lines.push(format!("extension_connected: {extension_connected}"));
             }
+            if let Some(ids) = data.get("extension_ids").and_then(|v| v.as_array())
+                && !ids.is_empty()
+            {
+                let ids: Vec<&str> = ids.iter().filter_map(|v| v.as_str()).collect();
+                lines.push(format!("extension_ids: {}", ids.join(", ")));
+            }
             lines.push(format!(
                 "required_version: >= {}",
                 crate::EXTENSION_PROTOCOL_MIN_VERSION
@@ -468,6 +601,9 @@ fn format_data_fields(command: &str, data: &Value, lines: &mut Vec<String>) {
             if let Some(title) = data.get("title").and_then(|v| v.as_str()) {
                 lines.push(format!("title: {title}"));
             }
+            if let Some(status) = data.pointer("/response/status").and_then(|v| v.as_u64()) {
+                lines.push(format!("status: {status}"));
+            }
         }
         "browser type" | "browser fill" => {
             if let Some(sel) = data.pointer("/target/selector").and_then(|v| v.as_str()) {
@@ -581,6 +717,17 @@ fn format_data_fields(command: &str, data: &Value, lines: &mut Vec<String>) {
                 lines.push(format!("count: {count}"));
             }
         }
+        "browser dialog" => {
+            if let Some(kind) = data.pointer("/dialog/type").and_then(|v| v.as_str()) {
+                lines.push(format!("type: {kind}"));
+            }
+            if let Some(msg) = data.pointer("/dialog/message").and_then(|v| v.as_str()) {
+                lines.push(format!("message: {msg}"));
+            }
+            if let Some(accepted) = data.get("accepted").and_then(|v| v.as_bool()) {
+                lines.push(format!("accepted: {accepted}"));
+            }
+        }
         "browser press" => {
             if let Some(keys) = data.get("keys").and_then(|v| v.as_str()) {
                 lines.push(format!("keys: {keys}"));
@@ -598,8 +745,16 @@ fn format_data_fields(command: &str, data: &Value, lines: &mut Vec<String>) {
             if let Some(path) = data.get("path").and_then(|v| v.as_str()) {
                 lines.push(format!("output saved to {path}"));
             }
+            if let Some(diff) = data.get("diff") {
+                let diff: SnapshotDiff = serde_json::from_value(diff.clone()).unwrap_or_default();
+                lines.extend(snapshot_diff_lines(
+                    &diff,
+                    data.get("has_baseline").and_then(|v| v.as_bool()) != Some(false),
+                ));
+            }
         }
-        "browser html" | "browser text" | "browser value" | "browser attr" => {
+        "browser html" | "browser text" | "browser value" | "browser attr" | "browser exists"
+        | "browser visible" => {
             if let Some(val) = data.get("value") {
                 lines.push(text_scalar(val));
             }
@@ -615,6 +770,30 @@ fn format_data_fields(command: &str, data: &Value, lines: &mut Vec<String>) {
             if let (Some(w), Some(h)) = (width, height) {
                 lines.push(format!("{w}x{h}"));
             }
+            if let Some(profile) = data.get("profile").and_then(|v| v.as_str()) {
+                lines.push(format!("saved for profile {profile}"));
+            }
+        }
+        "browser emulate" => {
+            if let Some(device) = data.get("device").and_then(|v| v.as_str()) {
+                lines.push(format!("device: {device}"));
+            }
+            let width = data.get("width").and_then(|v| v.as_u64());
+            let height = data.get("height").and_then(|v| v.as_u64());
+            if let (Some(w), Some(h)) = (width, height) {
+                lines.push(format!("viewport: {w}x{h}"));
+            }
+            if let Some(dpr) = data.get("device_scale_factor").and_then(|v| v.as_f64()) {
+                lines.push(format!("scale: {dpr}"));
+            }
+            if let Some(true) = data.get("reset").and_then(|v| v.as_bool()) {
+                lines.push("reset: true".to_string());
+            }
+        }
+        "browser throttle" => {
+            if let Some(preset) = data.get("preset").and_then(|v| v.as_str()) {
+                lines.push(format!("preset: {preset}"));
+            }
         }
         "browser attrs" => {
             if let Some(sel) = data.pointer("/target/selector").and_then(|v| v.as_str()) {
@@ -757,21 +936,22 @@ fn format_data_fields(command: &str, data: &Value, lines: &mut Vec<String>) {
         }
         "browser inspect-point" => {
             // §10.11: role "name" / selector / point
-            if let Some(element) = data.get("element") {
-                let role = element.get("role").and_then(|v| v.as_str()).unwrap_or("");
-                let name = element.get("name").and_then(|v| v.as_str()).unwrap_or("");
-                if !name.is_empty() {
-                    lines.push(format!("{role} \"{name}\""));
+            let inspected: InspectResult = serde_json::from_value(data.clone()).unwrap_or_default();
+            if let Some(element) = inspected.element {
+                if !element.name.is_empty() {
+                    lines.push(format!("{} \"{}\"", element.role, element.name));
                 } else {
-                    lines.push(role.to_string());
+                    lines.push(element.role);
+                }
+                if !element.selector.is_empty() {
+                    lines.push(format!("selector: {}", element.selector));
                 }
-                if let Some(sel) = element.get("selector").and_then(|v| v.as_str()) {
-                    lines.push(format!("selector: {sel}"));
+                let flags = element.state.set_flags();
+                if !flags.is_empty() {
+                    lines.push(format!("state: {}", flags.join(", ")));
                 }
             }
-            if let Some(point) = data.get("point") {
-                let x = point.get("x").and_then(|v| v.as_f64()).unwrap_or(0.0);
-                let y = point.get("y").and_then(|v| v.as_f64()).unwrap_or(0.0);
+            if let Some(Point { x, y }) = inspected.point {
                 // Format as integers if they are whole numbers
                 if x.fract() == 0.0 && y.fract() == 0.0 {
                     lines.push(format!("point: {},{}", x as i64, y as i64));
@@ -972,6 +1152,14 @@ fn format_data_fields(command: &str, data: &Value, lines: &mut Vec<String>) {
         "browser cookies set" | "browser cookies delete" | "browser cookies clear" => {
             // is_action already emits "ok {command}"; no additional text fields needed
         }
+        "browser cookies export" | "browser cookies import" => {
+            if let Some(path) = data.get("path").and_then(|v| v.as_str()) {
+                lines.push(format!("path: {path}"));
+            }
+            if let Some(count) = data.get("affected").and_then(|v| v.as_u64()) {
+                lines.push(format!("count: {count}"));
+            }
+        }
         "browser local-storage list" | "browser session-storage list" => {
             let items = data.get("items").and_then(|v| v.as_array());
             let count = items.map(|v| v.len()).unwrap_or(0);
@@ -1130,10 +1318,23 @@ fn format_new_tab_opened_tabs(
 
 #[cfg(test)]
 mod tests {
-    use super::{ResponseContext, format_text};
+    use super::{ResponseContext, format_text, set_pretty_json, to_json_string};
     use crate::action_result::ActionResult;
     use serde_json::json;
 
+    #[test]
+    fn json_output_is_compact_unless_pretty() {
+        let value = json!({ "ok": true, "data": { "n": 1 } });
+        assert_eq!(
+            to_json_string(&value).unwrap(),
+            r#"{"data":{"n":1},"ok":true}"#
+        );
+        set_pretty_json(true);
+        let pretty = to_json_string(&value).unwrap();
+        set_pretty_json(false);
+        assert!(pretty.contains("\n  \"data\": {"));
+    }
+
     #[test]
     fn browser_eval_text_renders_string_value() {
         let context = Some(ResponseContext {
@@ -1166,6 +1367,103 @@ mod tests {
         assert_eq!(text, "[s1 t2] https://example.com/page\n4");
     }
 
+    #[test]
+    fn browser_goto_text_renders_document_status() {
+        let context = Some(ResponseContext {
+            session_id: "s1".to_string(),
+            tab_id: Some("t1".to_string()),
+            window_id: None,
+            url: Some("https://example.com/login".to_string()),
+            title: Some("Sign in".to_string()),
+        });
+        let result = ActionResult::ok(json!({
+            "kind": "goto",
+            "title": "Sign in",
+            "response": { "status": 404, "url": "https://example.com/login" },
+        }));
+
+        let text = format_text("browser goto", &context, &result);
+
+        assert_eq!(
+            text,
+            "[s1 t1] https://example.com/login\nok browser goto\ntitle: Sign in\nstatus: 404"
+        );
+    }
+
+    #[test]
+    fn browser_profiles_list_text_shows_status_per_profile() {
+        let result = ActionResult::ok(json!({
+            "total_profiles": 2,
+            "profiles": [
+                {
+                    "name": "actionbook",
+                    "running": true,
+                    "sessions": ["s1"],
+                    "cdp_port": 9222,
+                    "last_used": "2026-10-01T08:00:00.000Z",
+                },
+                { "name": "work", "running": false, "sessions": [], "cdp_port": null, "last_used": null },
+            ],
+        }));
+
+        let text = format_text("browser profiles list", &None, &result);
+
+        assert_eq!(
+            text,
+            "2 profiles\n[actionbook]\nstatus: running (s1)\ncdp_port: 9222\n\
+             last_used: 2026-10-01T08:00:00.000Z\n[work]\nstatus: idle"
+        );
+    }
+
+    #[test]
+    fn browser_snapshot_diff_text_lists_changes() {
+        let result = ActionResult::ok(json!({
+            "path": "/tmp/snapshot.yaml",
+            "has_baseline": true,
+            "diff": {
+                "added": [{ "ref": "e4", "role": "dialog", "name": "Sign in", "value": "" }],
+                "removed": [{ "ref": "e3", "role": "link", "name": "Help", "value": "" }],
+                "changed": [{
+                    "before": { "ref": "e2", "role": "textbox", "name": "Email", "value": "" },
+                    "after": { "ref": "e2", "role": "textbox", "name": "Email", "value": "a@b.c", "focused": true },
+                }],
+            },
+        }));
+
+        let text = format_text("browser snapshot", &None, &result);
+
+        assert!(
+            text.ends_with(
+                "output saved to /tmp/snapshot.yaml\n\
+                 diff: 1 added, 1 removed, 1 changed\n\
+                 + dialog \"Sign in\" [ref=e4]\n\
+                 - link \"Help\" [ref=e3]\n\
+                 ~ textbox \"Email\" [ref=e2]: value \"\" -> \"a@b.c\"; state [] -> [focused]"
+            ),
+            "unexpected text: {text}"
+        );
+    }
+
+    #[test]
+    fn browser_inspect_point_text_renders_state_flags() {
+        let result = ActionResult::ok(json!({
+            "element": {
+                "role": "button",
+                "name": "Submit",
+                "selector": "@e4",
+                "disabled": true,
+            },
+            "point": { "x": 10, "y": 20 },
+        }));
+
+        let text = format_text("browser inspect-point", &None, &result);
+
+        assert!(
+            text.ends_with("button \"Submit\"\nselector: @e4\nstate: disabled\npoint: 10,20"),
+            "unexpected text: {text}"
+        );
+    }
+
     #[test]
     fn browser_new_tab_batch_text_renders_summary_without_action_header() {
         let context = Some(ResponseContext {
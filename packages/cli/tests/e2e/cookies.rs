@@ -10,6 +10,7 @@ const SECONDARY_COOKIE: &str = "secondary_cookie";
 const DELETE_COOKIE: &str = "delete_cookie";
 const CLEAR_COOKIE: &str = "clear_cookie";
 const EXPIRES_TS: &str = "2000000000";
+const EXPORT_COOKIE: &str = "export_cookie";
 
 fn start_session(url: &str) -> (String, String) {
     let (sid, profile) = unique_session("s");
@@ -625,6 +626,102 @@ fn cookies_clear_json_happy_path() {
     );
 }
 
+#[test]
+fn cookies_export_then_import_into_another_session() {
+    if skip() {
+        return;
+    }
+
+    let base_url = url_a();
+    let (src_sid, _src_tid) = start_session(&base_url);
+    let _src_guard = SessionGuard::new(&src_sid);
+    set_cookie(
+        &src_sid,
+        EXPORT_COOKIE,
+        "delta",
+        &[
+            "--domain",
+            "127.0.0.1",
+            "--path",
+            "/",
+            "--http-only",
+            "--same-site",
+            "Lax",
+            "--expires",
+            EXPIRES_TS,
+        ],
+        10,
+    );
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let file = dir.path().join("cookies.json");
+    let file_str = file.to_string_lossy().to_string();
+    let out = headless_json(
+        &[
+            "browser",
+            "cookies",
+            "export",
+            &file_str,
+            "--session",
+            &src_sid,
+            "--domain",
+            "127.0.0.1",
+        ],
+        10,
+    );
+    assert_success(&out, "cookies export");
+    let v = parse_json(&out);
+    assert_eq!(v["command"], "browser cookies export");
+    assert_eq!(v["data"]["path"], file_str.as_str());
+    assert!(v["data"]["affected"].as_u64().unwrap_or(0) >= 1);
+    let exported: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&file).unwrap()).unwrap();
+    assert!(exported.as_array().is_some(), "export must be a JSON array");
+
+    let (dst_sid, _dst_tid) = start_session(&base_url);
+    let _dst_guard = SessionGuard::new(&dst_sid);
+    let out = headless_json(
+        &[
+            "browser",
+            "cookies",
+            "import",
+            &file_str,
+            "--session",
+            &dst_sid,
+        ],
+        10,
+    );
+    assert_success(&out, "cookies import");
+    let v = parse_json(&out);
+    assert_eq!(v["command"], "browser cookies import");
+    assert!(v["data"]["affected"].as_u64().unwrap_or(0) >= 1);
+
+    let get_out = headless_json(
+        &[
+            "browser",
+            "cookies",
+            "get",
+            EXPORT_COOKIE,
+            "--session",
+            &dst_sid,
+        ],
+        10,
+    );
+    assert_success(&get_out, "cookies get after import");
+    let item = &parse_json(&get_out)["data"]["item"];
+    assert_cookie_shape(
+        item,
+        EXPORT_COOKIE,
+        "delta",
+        "127.0.0.1",
+        "/",
+        true,
+        false,
+        "Lax",
+    );
+    assert_eq!(item["expires"], 2_000_000_000.0);
+}
+
 #[test]
 fn cookies_session_not_found_json() {
     if skip() {
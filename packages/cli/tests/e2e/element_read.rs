@@ -267,6 +267,58 @@ fn text_json_full_page_happy_path() {
     assert!(text.contains("Primary article copy."));
 }
 
+#[test]
+fn text_normalizes_hidden_subtree_unless_raw() {
+    if skip() {
+        return;
+    }
+
+    let (sid, tid) = start_session();
+    let _guard = SessionGuard::new(&sid);
+    let js = r#"document.body.innerHTML = '<div id="hidden" style="display:none"><p>  First   line  </p><script>var leaked = 1;</script><style>.x{}</style><p>Second line</p></div>';
+void(0)"#;
+    let out = headless_json(
+        &["browser", "eval", js, "--session", &sid, "--tab", &tid],
+        10,
+    );
+    assert_success(&out, "inject hidden fixture");
+
+    let out = headless_json(
+        &[
+            "browser",
+            "text",
+            "#hidden",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        10,
+    );
+    assert_success(&out, "text normalized json");
+    let v = parse_json(&out);
+    assert_eq!(v["data"]["value"], "First line\nSecond line");
+
+    let out = headless_json(
+        &[
+            "browser",
+            "text",
+            "#hidden",
+            "--raw",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        10,
+    );
+    assert_success(&out, "text raw json");
+    let v = parse_json(&out);
+    let raw = v["data"]["value"].as_str().unwrap_or("");
+    assert!(raw.contains("var leaked = 1;"));
+    assert!(raw.contains("  First   line  "));
+}
+
 #[test]
 fn value_and_attr_json_and_text_happy_path() {
     if skip() {
@@ -445,6 +497,131 @@ fn value_and_attr_return_null_when_property_or_attribute_absent() {
     assert!(attr_text.contains("null"));
 }
 
+#[test]
+fn attr_property_reads_live_dom_state() {
+    if skip() {
+        return;
+    }
+
+    let (sid, tid) = start_session();
+    let _guard = SessionGuard::new(&sid);
+    inject_fixture(&sid, &tid);
+
+    let out = headless_json(
+        &[
+            "browser",
+            "eval",
+            "document.querySelector('#email').value = 'typed@example.com'; void(0)",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        10,
+    );
+    assert_success(&out, "update input value");
+
+    let attr = |name: &str, property: bool| {
+        let mut args = vec![
+            "browser",
+            "attr",
+            EMAIL_SELECTOR,
+            name,
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ];
+        if property {
+            args.push("--property");
+        }
+        let out = headless_json(&args, 10);
+        assert_success(&out, &format!("attr {name} property={property}"));
+        parse_json(&out)["data"]["value"].clone()
+    };
+
+    assert_eq!(attr("value", false), "user@example.com");
+    assert_eq!(attr("value", true), "typed@example.com");
+    assert_eq!(attr("disabled", true), false);
+    assert!(attr("noSuchProperty", true).is_null());
+}
+
+#[test]
+fn exists_and_visible_exit_code_reflects_result() {
+    if skip() {
+        return;
+    }
+
+    let (sid, tid) = start_session();
+    let _guard = SessionGuard::new(&sid);
+    inject_fixture(&sid, &tid);
+
+    let out = headless_json(
+        &[
+            "browser",
+            "eval",
+            "document.querySelector('#plain').style.display = 'none'; void(0)",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        10,
+    );
+    assert_success(&out, "hide plain block");
+
+    let check = |command: &str, selector: &str| {
+        let out = headless_json(
+            &[
+                "browser",
+                command,
+                selector,
+                "--session",
+                &sid,
+                "--tab",
+                &tid,
+            ],
+            10,
+        );
+        let v = parse_json(&out);
+        assert_eq!(v["ok"], true, "{command} {selector}: {v}");
+        assert_eq!(v["command"], format!("browser {command}"));
+        assert_eq!(v["data"]["target"]["selector"], selector);
+        let value = v["data"]["value"].as_bool().expect("boolean value");
+        assert_eq!(
+            out.status.code(),
+            Some(if value { 0 } else { 1 }),
+            "{command} {selector} exit code"
+        );
+        value
+    };
+
+    assert!(check("exists", EMAIL_SELECTOR));
+    assert!(check("exists", "#plain"));
+    assert!(!check("exists", "#missing"));
+    assert!(check("visible", EMAIL_SELECTOR));
+    assert!(!check("visible", "#plain"));
+    assert!(!check("visible", "#missing"));
+
+    // Errors exit 2 so they can't be mistaken for `false`.
+    for command in ["exists", "visible"] {
+        let out = headless_json(
+            &[
+                "browser",
+                command,
+                "#plain",
+                "--session",
+                "missing-session",
+                "--tab",
+                &tid,
+            ],
+            10,
+        );
+        assert_eq!(parse_json(&out)["ok"], false);
+        assert_eq!(out.status.code(), Some(2), "{command} error exit code");
+    }
+}
+
 #[test]
 fn value_session_not_found_json() {
     if skip() {
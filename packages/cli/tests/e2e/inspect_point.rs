@@ -236,6 +236,74 @@ fn inspect_point_json_happy_path() {
     close_session(&sid);
 }
 
+#[test]
+fn inspect_point_selector_json_happy_path() {
+    if skip() {
+        return;
+    }
+    let (sid, tid) = start_session();
+    let _guard = SessionGuard::new(&sid);
+    inject_fixture(&sid, &tid);
+
+    let out = headless_json(
+        &[
+            "browser",
+            "inspect-point",
+            "--selector",
+            "#target-btn",
+            "--parent-depth",
+            "1",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        10,
+    );
+    assert_success(&out, "inspect-point --selector json");
+    let v = parse_json(&out);
+
+    assert_eq!(v["command"], "browser inspect-point");
+    assert_eq!(v["data"]["target"]["selector"], "#target-btn");
+    assert_eq!(v["data"]["element"]["role"], "button");
+    assert_eq!(v["data"]["element"]["name"], "Test Button");
+    // Button spans (70,70)..(170,110); point is its centre.
+    assert_eq!(v["data"]["point"]["x"], 120.0);
+    assert_eq!(v["data"]["point"]["y"], 90.0);
+    assert_eq!(v["data"]["parents"].as_array().map(Vec::len), Some(1));
+
+    close_session(&sid);
+}
+
+#[test]
+fn inspect_point_selector_not_found_json() {
+    if skip() {
+        return;
+    }
+    let (sid, tid) = start_session();
+    let _guard = SessionGuard::new(&sid);
+    inject_fixture(&sid, &tid);
+
+    let out = headless_json(
+        &[
+            "browser",
+            "inspect-point",
+            "--selector",
+            "#missing",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        10,
+    );
+    assert_failure(&out, "inspect-point --selector missing");
+    let v = parse_json(&out);
+    assert_error_envelope(&v, "ELEMENT_NOT_FOUND");
+
+    close_session(&sid);
+}
+
 #[test]
 fn inspect_point_text_happy_path() {
     if skip() {
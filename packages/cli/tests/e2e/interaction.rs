@@ -1455,6 +1455,110 @@ fn click_selector_json() {
     close_session(&sid);
 }
 
+#[test]
+fn click_by_visible_text_prefers_exact_label() {
+    if skip() {
+        return;
+    }
+    let (sid, tid) = start_session(TEST_URL);
+    let _guard = SessionGuard::new(&sid);
+    install_click_fixture(&sid, &tid);
+
+    let out = headless_json(
+        &[
+            "browser",
+            "click",
+            "--text",
+            "click TARGET",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        15,
+    );
+    assert_success(&out, "click --text json");
+    let v = parse_json(&out);
+    assert_eq!(v["data"]["action"], "click");
+    assert_eq!(v["data"]["target"]["text"], "click TARGET");
+    assert_eq!(v["data"]["target"]["selector"], "#ab-click-btn");
+    assert_eq!(eval_value(&sid, &tid, "String(window.__ab_clicks)"), "1");
+
+    let out = headless_json(
+        &[
+            "browser",
+            "click",
+            "--text",
+            "Open",
+            "--exact",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        15,
+    );
+    assert_failure(&out, "click --text --exact without exact match");
+    let v = parse_json(&out);
+    assert_eq!(v["error"]["code"], "ELEMENT_NOT_FOUND");
+    let near = v["error"]["details"]["near_matches"].as_array().unwrap();
+    assert!(
+        near.iter().any(|m| m["selector"] == "#ab-link"),
+        "near matches should include the partial link match: {near:?}"
+    );
+
+    close_session(&sid);
+}
+
+#[test]
+fn click_by_visible_text_ranks_tier_before_match() {
+    if skip() {
+        return;
+    }
+    let (sid, tid) = start_session(TEST_URL);
+    let _guard = SessionGuard::new(&sid);
+    eval_value(
+        &sid,
+        &tid,
+        r##"(() => {
+  const root = document.createElement('div');
+  root.innerHTML = `
+    <label>Zephyr</label><button id="ab-rank-btn">Zephyr draft</button>
+    <a id="ab-rank-link" href="#"><span tabindex="0">Quokka entry</span></a>
+    <p id="ab-rank-text">Marmot <b>meadow</b> trail</p>`;
+  document.body.appendChild(root);
+  return 'ok';
+})()"##,
+    );
+
+    let click_text = |text: &str| {
+        let out = headless_json(
+            &[
+                "browser",
+                "click",
+                "--text",
+                text,
+                "--session",
+                &sid,
+                "--tab",
+                &tid,
+            ],
+            15,
+        );
+        assert_success(&out, &format!("click --text {text}"));
+        parse_json(&out)["data"]["target"]["selector"].clone()
+    };
+
+    // A partial match on a button beats an exact match on a label.
+    assert_eq!(click_text("Zephyr"), "#ab-rank-btn");
+    // The link is kept over the less clickable focusable span inside it.
+    assert_eq!(click_text("Quokka entry"), "#ab-rank-link");
+    // Plain text spanning inline children resolves to the element that owns it.
+    assert_eq!(click_text("marmot meadow trail"), "#ab-rank-text");
+
+    close_session(&sid);
+}
+
 #[test]
 fn click_text() {
     if skip() {
@@ -4207,6 +4311,47 @@ fn press_json_single_key() {
     close_session(&sid);
 }
 
+#[test]
+fn press_json_key_sequence() {
+    if skip() {
+        return;
+    }
+    let (sid, tid) = start_session(TEST_URL);
+    let _guard = SessionGuard::new(&sid);
+    install_press_fixture(&sid, &tid);
+
+    let out = headless_json(
+        &[
+            "browser",
+            "press",
+            "Escape Enter",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        15,
+    );
+    assert_success(&out, "press json key sequence");
+    let v = parse_json(&out);
+
+    assert_press_success(&v, &sid, &tid, "Escape Enter");
+    assert_eq!(
+        eval_value(&sid, &tid, "String(window.__ab_press_keydown_count)"),
+        "2"
+    );
+    assert_eq!(
+        eval_value(
+            &sid,
+            &tid,
+            "window.__ab_press_events.indexOf('keydown:Escape:false:false') < window.__ab_press_events.indexOf('keydown:Enter:false:false') ? 'yes' : 'no'"
+        ),
+        "yes"
+    );
+
+    close_session(&sid);
+}
+
 #[test]
 fn press_text_chord() {
     if skip() {
@@ -5317,6 +5462,83 @@ fn upload_relative_path_json() {
     close_session(&sid);
 }
 
+#[test]
+fn upload_missing_file_json() {
+    if skip() {
+        return;
+    }
+    let (sid, tid) = start_session(TEST_URL);
+    let _guard = SessionGuard::new(&sid);
+    install_upload_fixture(&sid, &tid);
+    let missing = std::env::temp_dir()
+        .join("ab-upload-definitely-missing.txt")
+        .to_string_lossy()
+        .into_owned();
+
+    let out = headless_json(
+        &[
+            "browser",
+            "upload",
+            "#ab-upload-input",
+            &missing,
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        10,
+    );
+    assert_failure(&out, "upload missing file json");
+    let v = parse_json(&out);
+
+    assert_error_envelope(&v, "INVALID_ARGUMENT");
+    assert!(
+        v["error"]["message"]
+            .as_str()
+            .is_some_and(|m| m.contains("file not found")),
+        "message must name the missing file: {v}"
+    );
+
+    close_session(&sid);
+}
+
+#[test]
+fn upload_rejects_non_file_input_json() {
+    if skip() {
+        return;
+    }
+    let (_tmp, files) = create_upload_files(&["upload-a.txt"]);
+    let (sid, tid) = start_session(TEST_URL);
+    let _guard = SessionGuard::new(&sid);
+    install_upload_fixture(&sid, &tid);
+
+    let out = headless_json(
+        &[
+            "browser",
+            "upload",
+            "#ab-upload-fixture",
+            &files[0],
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        10,
+    );
+    assert_failure(&out, "upload non-file input json");
+    let v = parse_json(&out);
+
+    assert_error_envelope(&v, "INVALID_ARGUMENT");
+    assert!(
+        v["error"]["message"]
+            .as_str()
+            .is_some_and(|m| m.contains("is not an <input type=\"file\"> element")),
+        "message must explain the target is not a file input: {v}"
+    );
+
+    close_session(&sid);
+}
+
 #[test]
 fn upload_relative_path_text() {
     if skip() {
@@ -8271,3 +8493,45 @@ void(0)
 
     close_session(&sid);
 }
+
+#[test]
+fn dialog_json_accepts_prompt_with_text() {
+    if skip() {
+        return;
+    }
+    let (sid, tid) = start_session(TEST_URL);
+    let _guard = SessionGuard::new(&sid);
+    eval_value(
+        &sid,
+        &tid,
+        "setTimeout(() => { window.__ab_prompt_answer = prompt('Name?', 'Bob'); }, 300); 'scheduled'",
+    );
+
+    let out = headless_json(
+        &[
+            "browser",
+            "dialog",
+            "accept",
+            "--text",
+            "Alice",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        15,
+    );
+    assert_success(&out, "dialog accept prompt");
+    let v = parse_json(&out);
+    assert_eq!(v["command"], "browser dialog");
+    assert_eq!(v["data"]["accepted"], true);
+    assert_eq!(v["data"]["dialog"]["type"], "prompt");
+    assert_eq!(v["data"]["dialog"]["message"], "Name?");
+    assert_eq!(v["data"]["dialog"]["default_prompt"], "Bob");
+    assert_eq!(
+        eval_value(&sid, &tid, "String(window.__ab_prompt_answer)"),
+        "Alice"
+    );
+
+    close_session(&sid);
+}
@@ -90,6 +90,31 @@ fn nav_goto_json() {
     assert_meta(&v);
 }
 
+#[test]
+fn nav_goto_reports_document_response() {
+    if skip() {
+        return;
+    }
+    let (sid, tid) = start_session(&url_a());
+    let _guard = SessionGuard::new(&sid);
+
+    let url_b = url_b();
+    let out = headless_json(
+        &["browser", "goto", &url_b, "--session", &sid, "--tab", &tid],
+        30,
+    );
+    assert_success(&out, "goto json");
+    let v = parse_json(&out);
+
+    let response = &v["data"]["response"];
+    assert_eq!(response["status"], 200, "document status: {response}");
+    assert!(
+        response["url"].as_str().unwrap_or("").contains("page-b"),
+        "response.url should be the final document URL"
+    );
+    assert!(response["headers"].is_object());
+}
+
 #[test]
 fn nav_goto_text() {
     if skip() {
@@ -663,6 +688,35 @@ fn nav_reload_text() {
     assert!(text.contains("title:"), "must contain title:");
 }
 
+#[test]
+fn nav_reload_hard_json() {
+    if skip() {
+        return;
+    }
+    let (sid, tid) = start_session(&url_a());
+    let _guard = SessionGuard::new(&sid);
+
+    let out = headless_json(
+        &[
+            "browser",
+            "reload",
+            "--hard",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        30,
+    );
+    assert_success(&out, "reload --hard json");
+    let v = parse_json(&out);
+
+    assert_eq!(v["command"], "browser reload");
+    assert_nav_data(&v, "reload", false);
+    assert_eq!(v["data"]["hard"], true);
+    assert_eq!(v["data"]["from_url"], v["data"]["to_url"]);
+}
+
 #[test]
 fn nav_reload_preserves_url() {
     if skip() {
@@ -751,3 +751,65 @@ fn viewport_missing_tab_arg() {
     let out = headless_json(&["browser", "viewport", "--session", "any-sid"], 10);
     assert_failure(&out, "viewport missing --tab");
 }
+
+#[test]
+fn emulate_device_changes_viewport_then_reset_restores_it() {
+    if skip() {
+        return;
+    }
+    let (sid, tid) = start_session(URL_A);
+    let _guard = SessionGuard::new(&sid);
+
+    let before = parse_json(&headless_json(
+        &["browser", "viewport", "--session", &sid, "--tab", &tid],
+        10,
+    ));
+
+    let out = headless_json(
+        &[
+            "browser",
+            "emulate",
+            "iPhone 14",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        10,
+    );
+    assert_success(&out, "emulate iPhone 14");
+    let v = parse_json(&out);
+    assert_eq!(v["command"], "browser emulate");
+    assert_eq!(v["data"]["device"], "iPhone 14");
+    assert_eq!(v["data"]["mobile"], true);
+
+    let vp = parse_json(&headless_json(
+        &["browser", "viewport", "--session", &sid, "--tab", &tid],
+        10,
+    ));
+    assert_eq!(vp["data"]["width"], 390);
+    assert_eq!(vp["data"]["height"], 844);
+
+    let out = headless_json(
+        &[
+            "browser",
+            "emulate",
+            "--reset",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        10,
+    );
+    assert_success(&out, "emulate --reset");
+
+    let after = parse_json(&headless_json(
+        &["browser", "viewport", "--session", &sid, "--tab", &tid],
+        10,
+    ));
+    assert_eq!(after["data"]["width"], before["data"]["width"]);
+    assert_eq!(after["data"]["height"], before["data"]["height"]);
+
+    close_session(&sid);
+}
@@ -126,6 +126,77 @@ fn pdf_json_happy_path() {
     );
 }
 
+#[test]
+fn pdf_accepts_paper_and_margin_options() {
+    if skip() {
+        return;
+    }
+
+    let (sid, tid) = start_session();
+    let _guard = SessionGuard::new(&sid);
+    inject_fixture(&sid, &tid);
+
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let path = tmp.path().join("a4-landscape.pdf");
+    let path_str = path.to_string_lossy().to_string();
+
+    let out = headless_json(
+        &[
+            "browser",
+            "pdf",
+            &path_str,
+            "--format",
+            "a4",
+            "--landscape",
+            "--margin-top",
+            "0.5",
+            "--print-background",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        30,
+    );
+    assert_success(&out, "pdf with options");
+    let v = parse_json(&out);
+    assert_eq!(v["data"]["artifact"]["path"], path_str);
+    assert!(
+        std::fs::metadata(&path)
+            .expect("pdf file should exist")
+            .len()
+            > 0
+    );
+}
+
+#[test]
+fn pdf_rejects_unknown_paper_format() {
+    if skip() {
+        return;
+    }
+
+    let (sid, tid) = start_session();
+    let _guard = SessionGuard::new(&sid);
+
+    let out = headless_json(
+        &[
+            "browser",
+            "pdf",
+            "/tmp/ab-unknown-format.pdf",
+            "--format",
+            "b5",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        30,
+    );
+    assert_failure(&out, "pdf unknown format");
+    let v = parse_json(&out);
+    assert_error_envelope(&v, "INVALID_ARGUMENT");
+}
+
 #[test]
 fn pdf_text_output() {
     if skip() {
@@ -302,6 +302,76 @@ fn screenshot_selector() {
     close_session(&sid);
 }
 
+#[test]
+fn screenshot_clip_region() {
+    if skip() {
+        return;
+    }
+    let (sid, tid) = start_session();
+    let _guard = SessionGuard::new(&sid);
+    inject_fixture(&sid, &tid);
+
+    let tmp = tempfile::NamedTempFile::new().unwrap();
+    let path = format!("{}.png", tmp.path().to_string_lossy());
+    drop(tmp);
+
+    let out = headless_json(
+        &[
+            "browser",
+            "screenshot",
+            &path,
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+            "--clip",
+            "0,0,200,100",
+        ],
+        15,
+    );
+    assert_success(&out, "screenshot clip");
+    let v = parse_json(&out);
+    let written = v["data"]["artifact"]["path"].as_str().unwrap().to_string();
+
+    // PNG IHDR: width and height are big-endian u32s at bytes 16..24.
+    let bytes = std::fs::read(&written).expect("read clipped screenshot");
+    let width = u32::from_be_bytes(bytes[16..20].try_into().unwrap());
+    let height = u32::from_be_bytes(bytes[20..24].try_into().unwrap());
+    assert_eq!((width, height), (200, 100));
+
+    let _ = std::fs::remove_file(&written);
+    close_session(&sid);
+}
+
+#[test]
+fn screenshot_clip_rejects_malformed_region() {
+    if skip() {
+        return;
+    }
+    let (sid, tid) = start_session();
+    let _guard = SessionGuard::new(&sid);
+
+    let out = headless_json(
+        &[
+            "browser",
+            "screenshot",
+            "/tmp/ab-clip-invalid.png",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+            "--clip",
+            "0,0,200",
+        ],
+        15,
+    );
+    assert_failure(&out, "screenshot malformed clip");
+    let v = parse_json(&out);
+    assert_error_envelope(&v, "INVALID_ARGUMENT");
+
+    close_session(&sid);
+}
+
 #[test]
 fn screenshot_annotate() {
     if skip() {
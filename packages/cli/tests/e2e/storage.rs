@@ -472,6 +472,75 @@ fn storage_clear_json_happy_path(kind: StorageKind, key: &str, value: &str) {
     assert!(get_v["data"]["item"].is_null(), "key should be cleared");
 }
 
+#[test]
+fn local_storage_clear_all_json() {
+    storage_clear_all_json(LOCAL);
+}
+
+#[test]
+fn session_storage_clear_all_json() {
+    storage_clear_all_json(SESSION);
+}
+
+fn storage_clear_all_json(kind: StorageKind) {
+    if skip() {
+        return;
+    }
+
+    let base_url = url_a();
+    let (sid, tid) = start_session(&base_url);
+    let _guard = SessionGuard::new(&sid);
+
+    let before = parse_json(&headless_json(
+        &[
+            "browser",
+            kind.cli_name,
+            "list",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        10,
+    ));
+    let existing = before["data"]["items"].as_array().map_or(0, |a| a.len());
+
+    set_storage(kind, &sid, &tid, "clear-all-a", "1");
+    set_storage(kind, &sid, &tid, "clear-all-b", "2");
+
+    let out = headless_json(
+        &[
+            "browser",
+            kind.cli_name,
+            "clear",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        10,
+    );
+    assert_success(&out, &format!("{} clear all json", kind.cli_name));
+    let v = parse_json(&out);
+    assert_eq!(v["command"], command_name(kind, "clear"));
+    assert_eq!(v["data"]["action"], "clear");
+    assert_eq!(v["data"]["affected"], existing as u64 + 2);
+
+    let list = parse_json(&headless_json(
+        &[
+            "browser",
+            kind.cli_name,
+            "list",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+        ],
+        10,
+    ));
+    assert_eq!(list["data"]["items"], serde_json::json!([]));
+}
+
 #[test]
 fn local_storage_session_not_found_json() {
     if skip() {
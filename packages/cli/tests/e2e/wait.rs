@@ -157,6 +157,48 @@ fn wait_element_json_happy_path() {
     assert_eq!(v["data"]["observed_value"]["selector"], ELEMENT_SELECTOR);
 }
 
+#[test]
+fn wait_element_accepts_xpath_selector() {
+    if skip() {
+        return;
+    }
+
+    let (sid, tid) = start_session("about:blank");
+    let _guard = SessionGuard::new(&sid);
+    let js = r#"setTimeout(() => {
+  const btn = document.createElement('button');
+  btn.textContent = 'Submit';
+  document.body.appendChild(btn);
+}, 150);
+void(0)"#;
+    let out = headless_json(
+        &["browser", "eval", js, "--session", &sid, "--tab", &tid],
+        10,
+    );
+    assert_success(&out, "schedule delayed button");
+
+    let xpath = "//button[text()='Submit']";
+    let out = headless_json(
+        &[
+            "browser",
+            "wait",
+            "element",
+            xpath,
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+            "--timeout",
+            "5000",
+        ],
+        10,
+    );
+    assert_success(&out, "wait element xpath");
+    let v = parse_json(&out);
+    assert_eq!(v["data"]["satisfied"], true);
+    assert_eq!(v["data"]["observed_value"]["selector"], xpath);
+}
+
 #[test]
 fn wait_element_text_output() {
     if skip() {
@@ -977,6 +1019,43 @@ fn wait_network_idle_succeeds_after_quiet_window_when_no_new_requests() {
     );
 }
 
+#[test]
+fn wait_network_idle_honours_custom_idle_window() {
+    if skip() {
+        return;
+    }
+
+    let (sid, tid) = start_session("about:blank");
+    let _guard = SessionGuard::new(&sid);
+
+    let out = headless_json(
+        &[
+            "browser",
+            "wait",
+            "network-idle",
+            "--idle",
+            "1500",
+            "--session",
+            &sid,
+            "--tab",
+            &tid,
+            "--timeout",
+            "4000",
+        ],
+        10,
+    );
+    assert_success(&out, "wait network-idle --idle 1500");
+    let v = parse_json(&out);
+    let elapsed_ms = v["data"]["elapsed_ms"].as_u64().unwrap_or_default();
+
+    assert_eq!(v["data"]["satisfied"], true);
+    assert_eq!(v["data"]["observed_value"]["idle_ms"], 1500);
+    assert!(
+        (1_500..3_000).contains(&elapsed_ms),
+        "elapsed_ms should reflect the custom quiet window, got {elapsed_ms}"
+    );
+}
+
 #[test]
 fn wait_network_idle_times_out_for_post_start_non_lazy_incomplete_image() {
     if skip() {
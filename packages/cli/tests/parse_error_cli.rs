@@ -0,0 +1,66 @@
+use assert_cmd::Command;
+use serde_json::Value;
+
+#[test]
+fn json_mode_reports_argument_errors_as_envelope() {
+    let output = Command::cargo_bin("actionbook")
+        .expect("binary exists")
+        .args(["--json", "browser", "click", "#login"])
+        .output()
+        .expect("run browser click without a session");
+
+    assert!(!output.status.success(), "missing arguments must fail");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let envelope: Value = serde_json::from_str(stdout.trim())
+        .unwrap_or_else(|e| panic!("stdout should be a JSON envelope ({e}): {stdout}"));
+    assert_eq!(envelope["ok"], false);
+    assert_eq!(envelope["command"], "browser click");
+    assert_eq!(envelope["error"]["code"], "INVALID_ARGUMENT");
+    assert_eq!(
+        envelope["error"]["details"]["kind"],
+        "MissingRequiredArgument"
+    );
+    assert!(
+        envelope["error"]["message"]
+            .as_str()
+            .is_some_and(|m| m.contains("--session")),
+        "message should describe the missing argument: {stdout}"
+    );
+}
+
+#[test]
+fn json_mode_envelope_names_the_parsed_subcommand() {
+    let output = Command::cargo_bin("actionbook")
+        .expect("binary exists")
+        .args([
+            "browser",
+            "--timeout",
+            "5000",
+            "wait",
+            "element",
+            "--json",
+            "--bogus",
+        ])
+        .output()
+        .expect("run browser wait element with an unknown flag");
+
+    assert!(!output.status.success(), "unknown flag must fail");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let envelope: Value = serde_json::from_str(stdout.trim())
+        .unwrap_or_else(|e| panic!("stdout should be a JSON envelope ({e}): {stdout}"));
+    assert_eq!(envelope["command"], "browser wait element");
+}
+
+#[test]
+fn text_mode_keeps_clap_usage_on_stderr() {
+    let output = Command::cargo_bin("actionbook")
+        .expect("binary exists")
+        .args(["browser", "click", "#login"])
+        .output()
+        .expect("run browser click without a session");
+
+    assert!(!output.status.success(), "missing arguments must fail");
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Usage:"));
+}